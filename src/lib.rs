@@ -6,7 +6,10 @@
 //!
 //! The path taken for transitions must be annotated (correctly) for transitions to work.
 //! Additonally, there can only be one transition between a source and a target type, as otherwise
-//! there would be duplicate trait implementations.
+//! there would be duplicate trait implementations, so conflicting paths result in an error
+//! pointing at both of them. Paths repeating a type without a function step in between, as well as
+//! paths whose generated impls would end up calling each other, are rejected since they would
+//! recurse infinitely at runtime. These checks are syntactic, so they do not see through aliases.
 //!
 //! The path is provided in the [`#[transitive]`] attribute along with a direction:
 //!
@@ -19,20 +22,898 @@
 //!
 //! # Conversions table:
 //!
-//! | Derived Type | Annotation                        | Will impl           | Conditions                                                                                                                |
-//! |--------------|-----------------------------------|---------------------|---------------------------------------------------------------------------------------------------------------------------|
-//! | A            | #[transitive(into(B, C, D))]      | `From<A> for D`     | `From<A> for B`; `From<B> for C`; `From<C> for D`                                                                         |
-//! | A            | #[transitive(from(D, C, B))]      | `From<D> for A`     | `From<D> for C`; `From<C> for B`; `From<B> for A`                                                                         |
-//! | A            | #[transitive(try_into(B, C, D))]  | `TryFrom<A> for D`  | `TryFrom<A> for B`; `TryFrom<B> for C`; `TryFrom<C> for D`; errors must impl `From<ErrType> for <D as TryFrom<C>>::Error` |
-//! | A            | #[transitive(try_from(D, C, B))]  | `TryFrom<D> for A`  | `TryFrom<D> for C`; `TryFrom<C> for B`; `TryFrom<B> for A`; errors must impl `From<ErrType> for <A as TryFrom<B>>::Error` |
+//! | Derived Type | Annotation                                         | Will impl                                                                                 | Conditions                                                                                                                                                        |
+//! |--------------|----------------------------------------------------|-------------------------------------------------------------------------------------------|-------------------------------------------------------------------------------------------------------------------------------------------------------------------|
+//! | A            | #[transitive(into(B, C, D))]                       | `From<A> for D`                                                                           | `From<A> for B`; `From<B> for C`; `From<C> for D`                                                                                                                 |
+//! | A            | #[transitive(from(D, C, B))]                       | `From<D> for A`                                                                           | `From<D> for C`; `From<C> for B`; `From<B> for A`                                                                                                                 |
+//! | A            | #[transitive(try_into(B, C, D))]                   | `TryFrom<A> for D`                                                                        | `TryFrom<A> for B`; `TryFrom<B> for C`; `TryFrom<C> for D`; errors must impl `From<ErrType> for <D as TryFrom<C>>::Error`                                         |
+//! | A            | #[transitive(try_from(D, C, B))]                   | `TryFrom<D> for A`                                                                        | `TryFrom<D> for C`; `TryFrom<C> for B`; `TryFrom<B> for A`; errors must impl `From<ErrType> for <A as TryFrom<B>>::Error`                                         |
+//! | A            | #[transitive(async_try_from(D, C, B))]             | `AsyncTryFrom<D> for A`                                                                   | `AsyncTryFrom<D> for C`; `AsyncTryFrom<C> for B`; `AsyncTryFrom<B> for A`; errors must impl `From<ErrType> for <A as AsyncTryFrom<B>>::Error`                     |
+//! | A            | #[transitive(checked_from(D, C, B))]               | `fn checked_from_d(D) -> Option<A>` on `A`                                                | `TryFrom<D> for C`; `TryFrom<C> for B`; `TryFrom<B> for A`                                                                                                        |
+//! | A            | #[transitive(checked_into(B, C, D))]               | `fn checked_into_d(self) -> Option<D>` on `A`                                             | `TryFrom<A> for B`; `TryFrom<B> for C`; `TryFrom<C> for D`                                                                                                        |
+//! | A            | #[transitive(from_or_default(D, C, B))]            | `From<D> for A`                                                                           | `A: Default`; `TryFrom<D> for C`; `TryFrom<C> for B`; `TryFrom<B> for A`; errors must impl `From<ErrType> for <A as TryFrom<B>>::Error`                           |
+//! | A            | #[transitive(from_or_else(D, C, B, fallback = f))] | `From<D> for A`                                                                           | `TryFrom<D> for C`; `TryFrom<C> for B`; `TryFrom<B> for A`; errors must impl `From<ErrType> for <A as TryFrom<B>>::Error`; `f: fn(<A as TryFrom<B>>::Error) -> A` |
+//! | A            | #[transitive(cast_ref(B, C))]                      | `AsRef<C> for A`; `fn cast_ref_c(&self) -> &C` and `fn cast_slice_c(&[A]) -> &[C]` on `A` | `A` is `#[repr(transparent)]` over `B`; `B` has `#[transitive(cast_ref(C))]`                                                                                      |
+//! | A            | #[transitive(from_str(C, B))]                      | `FromStr for A`                                                                           | `FromStr for C`; `TryFrom<C> for B`; `TryFrom<B> for A`; errors must impl `From<ErrType> for <A as TryFrom<B>>::Error`                                            |
+//! | A            | #[transitive(collect_from(D, C, B))]               | `FromIterator<D> for A`; `Extend<D> for A`                                                | `From<D> for C`; `From<C> for B`; `FromIterator<B> for A`; `Extend<B> for A`                                                                                      |
+//! | A            | #[transitive(default_via(C, B))]                   | `Default for A`                                                                           | `Default for C`; `From<C> for B`; `From<B> for A`                                                                                                                 |
+//! | A            | #[transitive(try_default_via(C, B))]               | `Default for A`                                                                           | `Default for C`; `TryFrom<C> for B`; `TryFrom<B> for A`; errors must impl `From<ErrType> for <A as TryFrom<B>>::Error`                                            |
+//! | A            | #[transitive(eq_via(B, C), with(D))]               | `PartialEq<D> for A`; `PartialEq<A> for D`                                                | `A: Clone`; `D: Clone`; `From<A> for B`; `From<B> for C`; `From<D> for C`; `C: PartialEq`                                                                         |
+//! | A            | #[transitive(ord_via(B, C), with(D))]              | `PartialOrd<D> for A`; `PartialOrd<A> for D`                                              | `A: Clone`; `D: Clone`; `From<A> for B`; `From<B> for C`; `From<D> for C`; `C: PartialOrd`                                                                        |
+//! | A            | #[transitive(display_via(B, C))]                   | `Display for A`                                                                           | `A: Clone`; `From<A> for B`; `From<B> for C`; `C: Display`                                                                                                        |
+//! | A            | #[transitive(debug_via(B, C))]                     | `Debug for A`                                                                             | `A: Clone`; `From<A> for B`; `From<B> for C`; `C: Debug`                                                                                                          |
+//! | A            | #[transitive(ops_via(B, C), ops(Add))]             | `Add for A`                                                                               | `From<A> for B`; `From<B> for C`; `C: Add<Output = C>`; `From<C> for B`; `From<B> for A`                                                                          |
+//! | A            | #[transitive(serde_into(B, C))]                    | `Serialize for A`                                                                         | `A: Clone`; `From<A> for B`; `From<B> for C`; `C: Serialize`                                                                                                      |
+//! | A            | #[transitive(serde_from(C, B))]                    | `Deserialize for A`                                                                       | `C: Deserialize`; `From<C> for B`; `From<B> for A`                                                                                                                |
+//! | A            | #[transitive(try_serde_into(B, C))]                | `Serialize for A`                                                                         | `A: Clone`; `TryFrom<A> for B`; `TryFrom<B> for C`; `C: Serialize`; errors must impl `From<ErrType> for <C as TryFrom<B>>::Error`, which must impl `Display`      |
+//! | A            | #[transitive(try_serde_from(C, B))]                | `Deserialize for A`                                                                       | `C: Deserialize`; `TryFrom<C> for B`; `TryFrom<B> for A`; errors must impl `From<ErrType> for <A as TryFrom<B>>::Error`, which must impl `Display`                |
 //!
 //!
 //! # Custom error type:
 //!
-//! For `try_from` and `try_into` annotations, the macro attribute can accept an `error = MyError`
-//! argument as the last element, like so: `#[transitive(try_into(A, B, C, error = MyError))]`. This
-//! overrides the default behavior and allows specifying a custom error type, but all the error
-//! types resulting from conversions must be convertible to this type.
+//! For `try_from`, `try_into` and the other fallible annotations, the macro attribute can accept an
+//! `error = MyError` argument as the last element, like so: `#[transitive(try_into(A, B, C, error =
+//! MyError))]`. This overrides the default behavior and allows specifying a custom error type, but
+//! all the error types resulting from conversions must be convertible to this type.
+//!
+//! # Function steps:
+//!
+//! A hop that is not a trait impl at all, like `u64 -> Duration` through `Duration::from_secs`, can
+//! be written as a function step in between the types of a `from`, `into`, `try_from` or
+//! `try_into` path. Steps come in two forms, `fn path::to::function` and `via = |val| ..`, and are
+//! applied to the value right before it gets converted to the type that follows them, the
+//! conversion source being inferred from their output. A step returning a `Result` is marked with
+//! `try`, ie. `try fn parse_port` or `try via = |val| ..`, and is only allowed in fallible paths,
+//! where its error must convert to the error type of the path. Unmarked steps are infallible in
+//! every path. Since the type right after a step is unknown, fallible paths need a custom `error`
+//! when a step precedes their last hop.
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use transitive::Transitive;
+//!
+//! #[derive(Transitive)]
+//! #[transitive(into(u64, fn Duration::from_secs, Timeout))] // impl From<Secs> for Timeout
+//! struct Secs(u64);
+//! struct Timeout(Duration);
+//!
+//! impl From<Secs> for u64 {
+//!     fn from(val: Secs) -> Self {
+//!         val.0
+//!     }
+//! }
+//!
+//! impl From<Duration> for Timeout {
+//!     fn from(val: Duration) -> Self {
+//!         Self(val)
+//!     }
+//! }
+//!
+//! let timeout = Timeout::from(Secs(5));
+//! assert_eq!(timeout.0, Duration::from_secs(5));
+//! ```
+//!
+//! # Coercions:
+//!
+//! Unsizing a smart pointer, like `Box<T> -> Box<dyn Trait>`, is a coercion rather than a trait
+//! impl, so there is nothing for a hop to call. Marking a type with `coerce` makes the hop into it
+//! a typed `let` binding instead, which performs the coercion. Like `plain`, the marker applies to
+//! the hop into the marked type, so it works the same in `from` and `into` paths. Coercions never
+//! fail, so they are [`Infallible`](core::convert::Infallible) in fallible paths.
+//!
+//! ```
+//! use transitive::Transitive;
+//!
+//! trait Handler {
+//!     fn handle(&self) -> String;
+//! }
+//!
+//! #[derive(Transitive)]
+//! #[transitive(into(Box<Echo>, coerce Box<dyn Handler>, Registered))] // impl From<Echo> for Registered
+//! struct Echo;
+//! struct Registered(Box<dyn Handler>);
+//!
+//! impl Handler for Echo {
+//!     fn handle(&self) -> String {
+//!         "echo".to_owned()
+//!     }
+//! }
+//!
+//! impl From<Box<dyn Handler>> for Registered {
+//!     fn from(val: Box<dyn Handler>) -> Self {
+//!         Self(val)
+//!     }
+//! }
+//!
+//! assert_eq!(Registered::from(Echo).0.handle(), "echo");
+//! ```
+//!
+//! # Custom traits:
+//!
+//! The `from`, `into`, `try_from` and `try_into` paths can go through conversion traits of your
+//! own, as long as they have the same shape as [`From`] or [`TryFrom`]. Adding the `trait =
+//! MyTrait` and `method = my_method` options to a `#[transitive(..)]` attribute makes all of its
+//! paths call `MyTrait::my_method` at each hop and implement `MyTrait` instead. Fallible traits can
+//! also take an `error_name = MyError` option when their associated error type is not named
+//! `Error`.
+//!
+//! ```
+//! use transitive::Transitive;
+//!
+//! pub trait FromProto<T> {
+//!     fn from_proto(val: T) -> Self;
+//! }
+//!
+//! #[derive(Transitive)]
+//! #[transitive(from(u64, Id), trait = FromProto, method = from_proto)] // impl FromProto<u64> for User
+//! struct User(Id);
+//! struct Id(u64);
+//!
+//! impl FromProto<u64> for Id {
+//!     fn from_proto(val: u64) -> Self {
+//!         Self(val)
+//!     }
+//! }
+//!
+//! impl FromProto<Id> for User {
+//!     fn from_proto(val: Id) -> Self {
+//!         Self(val)
+//!     }
+//! }
+//!
+//! let user = User::from_proto(1);
+//! assert_eq!(user.0 .0, 1);
+//! ```
+//!
+//! Conversions that need some context, like a registry or an interner, can use traits taking
+//! the context by reference as an extra argument, such as `trait ConvertWith<T, Ctx> { fn
+//! convert(val: T, ctx: &Ctx) -> Self; }`. The `with_context(ctx: &Ctx)` option passes the context
+//! type as the second generic argument of the trait and threads the same reference through every
+//! hop, reborrowing it for traits taking the context by mutable reference, ie. `with_context(ctx:
+//! &mut Ctx)`. A hop that does not need the context can be marked with `plain`, in which case it goes
+//! through [`From`] or [`TryFrom`] instead. The marker applies to the hop into the marked type, so
+//! `plain B` in `from(C, B)` means `From<C> for B` and in `into(B, C)` it means `From<A> for B`.
+//! The first type of a `from` path has no hop into it, so it cannot be marked.
+//!
+//! ```
+//! use transitive::Transitive;
+//!
+//! pub trait ConvertWith<T, Ctx> {
+//!     fn convert(val: T, ctx: &Ctx) -> Self;
+//! }
+//!
+//! struct Interner(Vec<&'static str>);
+//!
+//! #[derive(Transitive)]
+//! #[transitive(
+//!     from(&'static str, plain Name),
+//!     with_context(interner: &Interner),
+//!     trait = ConvertWith,
+//!     method = convert
+//! )] // impl ConvertWith<&'static str, Interner> for Id
+//! struct Id(usize);
+//! struct Name(&'static str);
+//!
+//! impl From<&'static str> for Name {
+//!     fn from(val: &'static str) -> Self {
+//!         Self(val)
+//!     }
+//! }
+//!
+//! impl ConvertWith<Name, Interner> for Id {
+//!     fn convert(val: Name, ctx: &Interner) -> Self {
+//!         Self(ctx.0.iter().position(|s| *s == val.0).unwrap())
+//!     }
+//! }
+//!
+//! let interner = Interner(vec!["foo", "bar"]);
+//! assert_eq!(Id::convert("bar", &interner).0, 1);
+//! ```
+//!
+//! # Enum variants:
+//!
+//! The `from` and `try_from` annotations can also be placed on enum variants with a single field.
+//! The path then continues through the type of the field and ends by wrapping the value in the
+//! variant, so `#[transitive(from(C, D))]` on the `A::V(B)` variant results in `impl From<C> for A`
+//! as `C -> D -> B -> A::V`. Without a custom error, `try_from` uses the error of the conversion to
+//! the field type.
+//!
+//! ```
+//! use transitive::Transitive;
+//!
+//! #[derive(Debug, PartialEq, Transitive)]
+//! enum AppError {
+//!     #[transitive(from(RawDbError))] // impl From<RawDbError> for AppError
+//!     Db(DbError),
+//!     #[transitive(from(std::io::ErrorKind, std::io::Error))]
+//!     // impl From<ErrorKind> for AppError
+//!     Io { kind: IoError },
+//! }
+//!
+//! struct RawDbError(u16);
+//! #[derive(Debug, PartialEq)]
+//! struct DbError(u16);
+//! #[derive(Debug, PartialEq)]
+//! struct IoError(String);
+//!
+//! impl From<RawDbError> for DbError {
+//!     fn from(val: RawDbError) -> Self {
+//!         Self(val.0)
+//!     }
+//! }
+//!
+//! impl From<std::io::Error> for IoError {
+//!     fn from(val: std::io::Error) -> Self {
+//!         Self(val.to_string())
+//!     }
+//! }
+//!
+//! assert_eq!(AppError::from(RawDbError(5)), AppError::Db(DbError(5)));
+//! assert!(matches!(
+//!     AppError::from(std::io::ErrorKind::NotFound),
+//!     AppError::Io { .. }
+//! ));
+//! ```
+//!
+//! # Struct fields:
+//!
+//! Similarly, `from` and `try_from` can be placed on a struct field, in which case the path goes
+//! through the type of the field and then builds the struct, without needing a conversion from
+//! the field type to the struct. Every other field must be marked with `#[transitive(default)]`
+//! and gets filled through [`Default`].
+//!
+//! ```
+//! use transitive::Transitive;
+//!
+//! #[derive(Transitive)]
+//! struct Wrapper {
+//!     #[transitive(from(u8, Mid))] // impl From<u8> for Wrapper
+//!     inner: Inner,
+//!     #[transitive(default)]
+//!     hits: u32,
+//! }
+//!
+//! struct Mid(u8);
+//! struct Inner(u8);
+//!
+//! impl From<u8> for Mid {
+//!     fn from(val: u8) -> Self {
+//!         Self(val)
+//!     }
+//! }
+//!
+//! impl From<Mid> for Inner {
+//!     fn from(val: Mid) -> Self {
+//!         Self(val.0)
+//!     }
+//! }
+//!
+//! let wrapper = Wrapper::from(7);
+//! assert_eq!((wrapper.inner.0, wrapper.hits), (7, 0));
+//! ```
+//!
+//! # Checked conversions:
+//!
+//! When only the success of a conversion matters, `checked_from` and `checked_into` generate
+//! inherent methods returning an [`Option`] instead of trait impls. `#[transitive(checked_from(D,
+//! C, B))]` on `A` generates `fn checked_from_d(val: D) -> Option<A>`, while
+//! `#[transitive(checked_into(B, C, D))]` generates `fn checked_into_d(self) -> Option<D>`, the
+//! methods sharing the visibility of `A`. The method names end with the snake cased name of the
+//! other type, rather than being a plain `checked_from` or `checked_into`, so that a type can have
+//! several checked paths without their methods colliding. Since the errors of the hops get
+//! discarded, they do not need a common conversion target.
+//!
+//! ```
+//! use transitive::Transitive;
+//!
+//! #[derive(Debug, PartialEq, Transitive)]
+//! #[transitive(checked_from(u32, Percent))] // fn checked_from_u32(val: u32) -> Option<Level>
+//! struct Level(u8);
+//! struct Percent(u8);
+//!
+//! struct NotPercent;
+//! struct TooLarge;
+//!
+//! impl TryFrom<u32> for Percent {
+//!     type Error = NotPercent;
+//!
+//!     fn try_from(val: u32) -> Result<Self, Self::Error> {
+//!         u8::try_from(val)
+//!             .ok()
+//!             .filter(|val| *val <= 100)
+//!             .map(Self)
+//!             .ok_or(NotPercent)
+//!     }
+//! }
+//!
+//! impl TryFrom<Percent> for Level {
+//!     type Error = TooLarge;
+//!
+//!     fn try_from(val: Percent) -> Result<Self, Self::Error> {
+//!         (val.0 <= 10).then_some(Self(val.0)).ok_or(TooLarge)
+//!     }
+//! }
+//!
+//! assert_eq!(Level::checked_from_u32(7), Some(Level(7)));
+//! assert_eq!(Level::checked_from_u32(70), None);
+//! assert_eq!(Level::checked_from_u32(700), None);
+//! ```
+//!
+//! # Fallback values:
+//!
+//! Sometimes a failed conversion should produce a sentinel value rather than an error.
+//! `#[transitive(from_or_default(D, C, B))]` generates an infallible `impl From<D> for A` that
+//! goes through the [`TryFrom`] chain and falls back to `A::default()` on failure.
+//! `#[transitive(from_or_else(D, C, B, fallback = my::fallback_fn))]` passes the error to the
+//! fallback function instead, which must return `A`. The error type is the same as for
+//! `try_from`, so it can be customized through `error = MyErr`.
+//!
+//! ```
+//! use transitive::Transitive;
+//!
+//! #[derive(Debug, Default, PartialEq, Transitive)]
+//! #[transitive(from_or_default(u32, Percent))] // impl From<u32> for Level
+//! #[transitive(from_or_else(u64, u32, Percent, error = LevelError, fallback = Level::clamp))] // impl From<u64> for Level
+//! struct Level(u8);
+//! struct Percent(u8);
+//!
+//! struct LevelError;
+//!
+//! impl Level {
+//!     fn clamp(_: LevelError) -> Self {
+//!         Self(10)
+//!     }
+//! }
+//!
+//! impl From<std::num::TryFromIntError> for LevelError {
+//!     fn from(_: std::num::TryFromIntError) -> Self {
+//!         Self
+//!     }
+//! }
+//!
+//! impl TryFrom<u32> for Percent {
+//!     type Error = LevelError;
+//!
+//!     fn try_from(val: u32) -> Result<Self, Self::Error> {
+//!         (val <= 100).then_some(Self(val as u8)).ok_or(LevelError)
+//!     }
+//! }
+//!
+//! impl TryFrom<Percent> for Level {
+//!     type Error = LevelError;
+//!
+//!     fn try_from(val: Percent) -> Result<Self, Self::Error> {
+//!         (val.0 <= 10).then_some(Self(val.0)).ok_or(LevelError)
+//!     }
+//! }
+//!
+//! assert_eq!(Level::from(7u32), Level(7));
+//! assert_eq!(Level::from(70u32), Level(0));
+//! assert_eq!(Level::from(70u64), Level(10));
+//! assert_eq!(Level::from(u64::MAX), Level(10));
+//! ```
+//!
+//! # Parsing:
+//!
+//! The `from_str` annotation works like `try_from`, but the first type in the path is parsed from a
+//! string slice through its [`FromStr`](core::str::FromStr) impl. The path may also consist of a
+//! single type, like `from_str(u64)`, which gets converted straight to the derived type. The
+//! derived type can then be obtained through [`str::parse`]:
+//!
+//! ```
+//! use std::{convert::Infallible, num::ParseIntError};
+//!
+//! use transitive::Transitive;
+//!
+//! #[derive(Transitive)]
+//! #[transitive(from_str(u64, Raw))] // impl FromStr for Port by doing &str -> u64 -> Raw -> Port
+//! struct Port(u16);
+//! struct Raw(u64);
+//!
+//! struct PortError;
+//!
+//! impl From<ParseIntError> for PortError {
+//!     fn from(val: ParseIntError) -> Self {
+//!         Self
+//!     }
+//! }
+//!
+//! impl From<Infallible> for PortError {
+//!     fn from(val: Infallible) -> Self {
+//!         match val {}
+//!     }
+//! }
+//!
+//! impl From<u64> for Raw {
+//!     fn from(val: u64) -> Self {
+//!         Self(val)
+//!     }
+//! }
+//!
+//! impl TryFrom<Raw> for Port {
+//!     type Error = PortError;
+//!
+//!     fn try_from(val: Raw) -> Result<Self, Self::Error> {
+//!         u16::try_from(val.0).map(Self).map_err(|_| PortError)
+//!     }
+//! }
+//!
+//! assert!("8080".parse::<Port>().is_ok());
+//! assert!("80800".parse::<Port>().is_err());
+//! ```
+//!
+//! # Default values:
+//!
+//! The `default_via` annotation implements [`Default`] for the derived type by converting the
+//! default value of the first type in the path. Its `try_default_via` counterpart goes through
+//! [`TryFrom`] conversions instead and, since [`Default`] cannot fail, panics if any of them does.
+//! The error type follows the same rules as for `try_from`. Both annotations accept a single type
+//! as well, like `default_via(RawConfig)`, which gets converted straight to the derived type.
+//!
+//! ```
+//! use transitive::Transitive;
+//!
+//! #[derive(Transitive)]
+//! #[transitive(default_via(RawConfig, Validated))] // impl Default for Config
+//! struct Config;
+//!
+//! #[derive(Default)]
+//! struct RawConfig;
+//! struct Validated;
+//!
+//! impl From<RawConfig> for Validated {
+//!     fn from(val: RawConfig) -> Self {
+//!         Self
+//!     }
+//! }
+//!
+//! impl From<Validated> for Config {
+//!     fn from(val: Validated) -> Self {
+//!         Self
+//!     }
+//! }
+//!
+//! let config: Config = Default::default();
+//! ```
+//!
+//! # Collections:
+//!
+//! The `collect_from` annotation is the element-wise counterpart of `from`. When derived on a
+//! collection type, it implements [`FromIterator`] and [`Extend`] for the first type in the path
+//! by converting each element to the last type in the path and delegating to the collection's own
+//! impls for that type. The element conversions support hop markers, but the last type cannot be
+//! marked since it goes through the collection's impls.
+//!
+//! ```
+//! use transitive::Transitive;
+//!
+//! #[derive(Transitive)]
+//! #[transitive(collect_from(u8, Id))] // impl FromIterator<u8> and Extend<u8> for Ids
+//! struct Ids(Vec<Id>);
+//! struct Id(u8);
+//!
+//! impl From<u8> for Id {
+//!     fn from(val: u8) -> Self {
+//!         Self(val)
+//!     }
+//! }
+//!
+//! impl FromIterator<Id> for Ids {
+//!     fn from_iter<I: IntoIterator<Item = Id>>(iter: I) -> Self {
+//!         Self(iter.into_iter().collect())
+//!     }
+//! }
+//!
+//! impl Extend<Id> for Ids {
+//!     fn extend<I: IntoIterator<Item = Id>>(&mut self, iter: I) {
+//!         self.0.extend(iter)
+//!     }
+//! }
+//!
+//! let mut ids: Ids = [1, 2].into_iter().collect();
+//! ids.extend([3]);
+//! ```
+//!
+//! # Comparisons:
+//!
+//! The `eq_via` and `ord_via` annotations compare the derived type with other types by converting
+//! both sides to a common type, the last one in the path. The types to compare with are given in
+//! a `with(..)` list next to the path, ie. `#[transitive(eq_via(B, C), with(D))]`, which applies to
+//! the comparison paths of the same attribute. They are converted directly to the common type.
+//! Symmetric impls are generated as well, so `A == D` and `D == A` both work. The path from the
+//! derived type to the common type is converted just like an `into` path, so it supports hop
+//! markers and function steps as well.
+//!
+//! Both sides are cloned before being converted, unless the `ref` flag is present, in which case
+//! the first conversion of each side is done from a reference, ie. `From<&A> for B` and
+//! `From<&D> for C`. Note that [`PartialOrd`] requires [`PartialEq`], so `ord_via` is typically
+//! paired with `eq_via`.
+//!
+//! ```
+//! use transitive::Transitive;
+//!
+//! #[derive(Transitive)]
+//! #[transitive(eq_via(Millimeters, ref), ord_via(Millimeters, ref), with(Feet))] // impl PartialEq<Feet> and PartialOrd<Feet> for Meters
+//! struct Meters(u64);
+//! struct Feet(u64);
+//!
+//! #[derive(PartialEq, PartialOrd)]
+//! struct Millimeters(u64);
+//!
+//! impl From<&Meters> for Millimeters {
+//!     fn from(val: &Meters) -> Self {
+//!         Self(val.0 * 1000)
+//!     }
+//! }
+//!
+//! impl From<&Feet> for Millimeters {
+//!     fn from(val: &Feet) -> Self {
+//!         Self(val.0 * 305)
+//!     }
+//! }
+//!
+//! assert!(Meters(61) == Feet(200));
+//! assert!(Feet(4) > Meters(1));
+//! ```
+//!
+//! # Formatting:
+//!
+//! The `display_via` and `debug_via` annotations implement [`Display`](core::fmt::Display) and
+//! [`Debug`] for the derived type by converting it along the path and delegating to the
+//! formatting impl of the last type. Just like for comparisons, the path is converted like an
+//! `into` path and the derived type is cloned unless the `ref` flag is present.
+//!
+//! ```
+//! use transitive::Transitive;
+//!
+//! #[derive(Transitive)]
+//! #[transitive(display_via(Raw, String, ref))] // impl Display for UserId
+//! struct UserId(u64);
+//! struct Raw(u64);
+//!
+//! impl From<&UserId> for Raw {
+//!     fn from(val: &UserId) -> Self {
+//!         Self(val.0)
+//!     }
+//! }
+//!
+//! impl From<Raw> for String {
+//!     fn from(val: Raw) -> Self {
+//!         format!("user-{}", val.0)
+//!     }
+//! }
+//!
+//! assert_eq!(UserId(1).to_string(), "user-1");
+//! ```
+//!
+//! # Operators:
+//!
+//! The `ops_via` annotation implements the operator traits listed in the `ops(..)` list next to it,
+//! ie. `#[transitive(ops_via(B), ops(Add))]`, by converting the operands to the last type in the
+//! path, applying the operator and converting the result back along the path. Conversions are
+//! therefore required in both directions, which is why the path cannot contain hop markers or
+//! function steps.
+//!
+//! The supported operators are the unary `Neg` and `Not` and the binary `Add`, `Sub`, `Mul`,
+//! `Div`, `Rem`, `BitAnd`, `BitOr`, `BitXor`, `Shl` and `Shr`. The right hand side of a binary
+//! operator is the derived type by default, but a different one can be provided, ie. `Mul<f64>`,
+//! in which case it gets converted directly to the last type in the path.
+//!
+//! ```
+//! use transitive::Transitive;
+//!
+//! #[derive(Debug, PartialEq, Transitive)]
+//! #[transitive(ops_via(f64), ops(Add, Sub, Mul<f64>, Neg))] // impl Add, Sub, Mul<f64> and Neg
+//! struct Meters(f64);
+//!
+//! impl From<Meters> for f64 {
+//!     fn from(val: Meters) -> Self {
+//!         val.0
+//!     }
+//! }
+//!
+//! impl From<f64> for Meters {
+//!     fn from(val: f64) -> Self {
+//!         Self(val)
+//!     }
+//! }
+//!
+//! assert_eq!(Meters(1.0) + Meters(2.0), Meters(3.0));
+//! assert_eq!(-(Meters(2.0) * 2.0), Meters(-4.0));
+//! ```
+//!
+//! # Serde:
+//!
+//! The `serde_into` and `serde_from` annotations are the multi-hop counterparts of serde's
+//! `#[serde(into = "..")]` and `#[serde(from = "..")]` container attributes. The derived type gets
+//! converted along the path and the last type gets serialized, respectively the first type gets
+//! deserialized and converted along the path. The `try_serde_into` and `try_serde_from` variants
+//! use [`TryFrom`] and turn the conversion error into a serde error through its
+//! [`Display`](core::fmt::Display) impl.
+//!
+//! The generated code refers to the `serde` crate, which must be a dependency of the crate using
+//! these annotations.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use transitive::Transitive;
+//!
+//! #[derive(Clone, Transitive)]
+//! #[transitive(serde_into(Raw, Wire))] // impl Serialize for Domain
+//! #[transitive(serde_from(Wire, Raw))] // impl Deserialize for Domain
+//! struct Domain(u64);
+//! struct Raw(u64);
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Wire {
+//!     value: u64,
+//! }
+//!
+//! impl From<Domain> for Raw {
+//!     fn from(val: Domain) -> Self {
+//!         Self(val.0)
+//!     }
+//! }
+//!
+//! impl From<Raw> for Wire {
+//!     fn from(val: Raw) -> Self {
+//!         Self { value: val.0 }
+//!     }
+//! }
+//!
+//! impl From<Wire> for Raw {
+//!     fn from(val: Wire) -> Self {
+//!         Self(val.value)
+//!     }
+//! }
+//!
+//! impl From<Raw> for Domain {
+//!     fn from(val: Raw) -> Self {
+//!         Self(val.0)
+//!     }
+//! }
+//!
+//! let json = serde_json::to_string(&Domain(1)).unwrap();
+//! assert_eq!(json, r#"{"value":1}"#);
+//! let domain: Domain = serde_json::from_str(&json).unwrap();
+//! ```
+//!
+//! When only a single field needs a transitive representation, the [`serde_with!`] macro generates
+//! a module to be used with serde's `#[serde(with = "..")]` field attribute. The path starts with
+//! the field type and ends with the type that actually gets serialized. Conversions are done along
+//! the path when serializing and back along it when deserializing, so [`From`] impls are required
+//! in both directions. Prefixing the module with `try` uses [`TryFrom`] impls instead, with every
+//! conversion error turned into a serde error. Since serde serializes the field through a
+//! reference, the field type must implement [`Clone`] so that the conversions can take it by value.
+//! The generated module glob imports its parent module, so the types in the path must be nameable
+//! from there. Since the path is converted both ways, it only takes plain types, without hop
+//! markers or function steps.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! transitive::serde_with!(mod timeout_secs = Timeout -> Secs -> u64);
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "timeout_secs")]
+//!     timeout: Timeout,
+//! }
+//!
+//! #[derive(Clone)]
+//! struct Timeout(std::time::Duration);
+//! struct Secs(u64);
+//!
+//! impl From<Timeout> for Secs {
+//!     fn from(val: Timeout) -> Self {
+//!         Self(val.0.as_secs())
+//!     }
+//! }
+//!
+//! impl From<Secs> for u64 {
+//!     fn from(val: Secs) -> Self {
+//!         val.0
+//!     }
+//! }
+//!
+//! impl From<u64> for Secs {
+//!     fn from(val: u64) -> Self {
+//!         Self(val)
+//!     }
+//! }
+//!
+//! impl From<Secs> for Timeout {
+//!     fn from(val: Secs) -> Self {
+//!         Self(std::time::Duration::from_secs(val.0))
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let json = r#"{"timeout":5}"#;
+//! let config: Config = serde_json::from_str(json).unwrap();
+//! assert_eq!(serde_json::to_string(&config).unwrap(), json);
+//! # }
+//! ```
+//!
+//! # Deref chains:
+//!
+//! The `deref_to` annotation walks a chain of [`Deref`](core::ops::Deref) impls instead, which is
+//! handy for layered smart pointer newtypes. `#[transitive(deref_to(B, C))]` on `A` generates an
+//! inherent `fn deref_to_c(&self) -> &C` that goes through `A -> B -> C`, the method name being
+//! the snake cased name of the last type and its visibility the one of `A`. Two flags can be added
+//! after the types:
+//! - `mut`, which also generates `fn deref_to_c_mut(&mut self) -> &mut C` through
+//!   [`DerefMut`](core::ops::DerefMut);
+//! - `as_ref`, which also implements `AsRef<C> for A`.
+//!
+//! ```
+//! use std::{ops::Deref, sync::Arc};
+//!
+//! use transitive::Transitive;
+//!
+//! #[derive(Transitive)]
+//! #[transitive(deref_to(Shared, Arc<Inner>, Inner, as_ref))] // fn deref_to_inner(&self) -> &Inner
+//! struct Handle(Shared);
+//! struct Shared(Arc<Inner>);
+//! struct Inner;
+//!
+//! impl Deref for Handle {
+//!     type Target = Shared;
+//!
+//!     fn deref(&self) -> &Self::Target {
+//!         &self.0
+//!     }
+//! }
+//!
+//! impl Deref for Shared {
+//!     type Target = Arc<Inner>;
+//!
+//!     fn deref(&self) -> &Self::Target {
+//!         &self.0
+//!     }
+//! }
+//!
+//! let handle = Handle(Shared(Arc::new(Inner)));
+//! let _: &Inner = handle.deref_to_inner();
+//! let _: &Inner = handle.as_ref();
+//! ```
+//!
+//! # Reference casts:
+//!
+//! Nested `#[repr(transparent)]` newtypes have the same layout as the innermost type, so they can
+//! be viewed as it without moving them. `#[transitive(cast_ref(B, C))]` on `A` generates
+//! `fn cast_ref_c(&self) -> &C` and `fn cast_slice_c(val: &[A]) -> &[C]`, along with `AsRef<C>`.
+//! The derive refuses to generate the cast unless `A` is `#[repr(transparent)]` over `B`, while
+//! the remaining layers are cast through the methods generated by `#[transitive(cast_ref(C))]` on
+//! `B`, so every layer of the path checks its own one. That check compares the field type with `B`
+//! syntactically, so `B` must be written exactly like the field type, not through an alias. The
+//! methods share the visibility of the type they are generated on, so those of `B` must be visible
+//! to `A`.
+//!
+//! ```
+//! use transitive::Transitive;
+//!
+//! #[derive(Transitive)]
+//! #[repr(transparent)]
+//! #[transitive(cast_ref(u64))] // fn cast_ref_u64(&self) -> &u64 and fn cast_slice_u64(val: &[Id]) -> &[u64]
+//! struct Id(u64);
+//!
+//! #[derive(Transitive)]
+//! #[repr(transparent)]
+//! #[transitive(cast_ref(Id, u64))] // fn cast_ref_u64(&self) -> &u64 and fn cast_slice_u64(val: &[UserId]) -> &[u64]
+//! struct UserId(Id);
+//!
+//! let ids = [UserId(Id(1)), UserId(Id(2))];
+//! assert_eq!(UserId::cast_slice_u64(&ids), &[1, 2]);
+//! assert_eq!(ids[0].cast_ref_u64(), &1);
+//! ```
+//!
+//! # Async conversions:
+//!
+//! Hops that are inherently async, like resolving an ID through a cache, can go through a trait
+//! with an `async fn`. Since a procedural macro crate cannot export traits, the default one,
+//! `AsyncTryFrom`, lives in the `transitive-async` companion crate, which must be added as a
+//! dependency:
+//!
+//! ```
+//! # use std::future::Future;
+//! trait AsyncTryFrom<T>: Sized {
+//!     type Error;
+//!
+//!     fn async_try_from(val: T) -> impl Future<Output = Result<Self, Self::Error>> + Send;
+//! }
+//! ```
+//!
+//! `#[transitive(async_try_from(D, C, B))]` then implements `AsyncTryFrom<D> for A`, awaiting each
+//! hop and following the same error rules as `try_from`. A trait of your own, like one whose
+//! futures need not be [`Send`], can be used instead through the `trait` and `method` options,
+//! ie. `trait = crate::cache::FetchFrom, method = fetch_from`. Hops that are synchronous can be
+//! marked with `plain` to call [`TryFrom`] directly.
+//!
+//! ```
+//! use transitive::Transitive;
+//! use transitive_async::AsyncTryFrom;
+//!
+//! #[derive(Transitive)]
+//! #[transitive(async_try_from(u64, plain UserId))] // impl AsyncTryFrom<u64> for User
+//! struct User(UserId);
+//! struct UserId(u64);
+//!
+//! impl From<u64> for UserId {
+//!     fn from(val: u64) -> Self {
+//!         Self(val)
+//!     }
+//! }
+//!
+//! impl AsyncTryFrom<UserId> for User {
+//!     type Error = std::convert::Infallible;
+//!
+//!     async fn async_try_from(val: UserId) -> Result<Self, Self::Error> {
+//!         Ok(Self(val))
+//!     }
+//! }
+//!
+//! let _future = User::async_try_from(1);
+//! ```
+//!
+//! # Lifting:
+//!
+//! A transitive `From<D> for A` does not give you `Option<D> -> Option<A>` or `Vec<D> -> Vec<A>`,
+//! and coherence forbids implementing most of those. Adding a `lift(..)` list after the types of a
+//! `from`, `into`, `try_from` or `try_into` path generates inherent helpers on the derived type,
+//! sharing its visibility, that apply the conversion element-wise. The supported containers are
+//! `Option`, `Vec`, `Result` (mapping the `Ok` value) and `Box`. For `#[transitive(from(D, C, B,
+//! lift(Option, Vec)))]` on `A` that means `A::from_option_d` and `A::from_vec_d`, while `into`
+//! paths generate `A::into_option_d` and so on, the fallible paths prefixing the names with `try_`.
+//! Since `Box` is a fundamental type, `from` and `try_from` paths implement `From<Box<D>> for
+//! Box<A>` (or `TryFrom`) instead.
+//!
+//! The helpers are named after the last segment of the other type only, so two paths whose helpers
+//! would share a name, like `from(Vec<u8>, ..)` and `from(Vec<u16>, ..)` both lifted to `Option`,
+//! get rejected. The `Vec` and `Box` containers are spelled through `std`, so lifting to them
+//! requires the standard library even though the rest of the generated code only relies on `core`.
+//!
+//! ```
+//! use transitive::Transitive;
+//!
+//! #[derive(Debug, PartialEq, Transitive)]
+//! #[transitive(from(u8, Level, lift(Option, Vec, Box)))] // impl From<u8> for Volume and helpers
+//! struct Volume(u8);
+//! struct Level(u8);
+//!
+//! impl From<u8> for Level {
+//!     fn from(val: u8) -> Self {
+//!         Self(val.min(10))
+//!     }
+//! }
+//!
+//! impl From<Level> for Volume {
+//!     fn from(val: Level) -> Self {
+//!         Self(val.0)
+//!     }
+//! }
+//!
+//! assert_eq!(Volume::from_option_u8(Some(12)), Some(Volume(10)));
+//! assert_eq!(Volume::from_vec_u8(vec![1, 2]), vec![Volume(1), Volume(2)]);
+//! assert_eq!(Box::<Volume>::from(Box::new(3)), Box::new(Volume(3)));
+//! ```
+//!
+//! # Distinct types check:
+//!
+//! The first and last types of a `from`, `into`, `try_from`, `try_into`, `async_try_from`,
+//! `checked_*`, `from_or_*` or `collect_from` path must not be the same type, which is checked at
+//! compile time by a single item per derive since the types could be aliases of one another. Any
+//! path can opt out of the check with a `no_distinct_check` flag after its types, like so:
+//! `#[transitive(from(D, C, B, no_distinct_check))]`.
+//!
+//! # Diagnostics:
+//!
+//! The code generated for each hop is spanned at the type it involves in the attribute, so a
+//! missing impl gets reported on that type rather than on the derive. Enabling the `diagnostics`
+//! feature, which requires Rust 1.78, goes further and uses `#[diagnostic::on_unimplemented]` to
+//! name the failing hop, like ``transitive hop 2 of 3: `B` must implement `From<C>` `` for a
+//! missing `From<C> for B` in `#[transitive(from(D, C, B))]`.
 //!
 //! # Examples:
 //!
@@ -217,13 +1098,14 @@
 //! B::try_from(D);
 //! ```
 
+mod serde_with;
 mod transitive;
 
 use proc_macro::TokenStream;
 use quote::ToTokens;
 use syn::parse_macro_input;
 
-use crate::transitive::TransitiveInput;
+use crate::{serde_with::SerdeWithInput, transitive::TransitiveInput};
 
 #[proc_macro_derive(Transitive, attributes(transitive))]
 pub fn transitive(input: TokenStream) -> TokenStream {
@@ -231,3 +1113,15 @@ pub fn transitive(input: TokenStream) -> TokenStream {
         .to_token_stream()
         .into()
 }
+
+/// Generates a module to be used with serde's `#[serde(with = "..")]` field attribute, converting
+/// the field along the path when serializing and back along it when deserializing. The field type
+/// must implement [`Clone`], as it gets cloned before being converted for serialization.
+///
+/// See the [crate level documentation](crate#serde) for details.
+#[proc_macro]
+pub fn serde_with(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as SerdeWithInput)
+        .to_token_stream()
+        .into()
+}
@@ -0,0 +1,144 @@
+//! The [`crate::serde_with`] macro. Its path is walked both ways, forward when serializing and
+//! backward when deserializing, so it only takes plain types. Hop markers and function steps only
+//! describe a single direction and the other paths do not need to reverse them, so rather than
+//! reusing the path lists of the derive, the `->` separated types are parsed here and anything
+//! else gets rejected with an error pointing at it.
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Error as SynError, Ident, Result as SynResult, Token, Type, Visibility,
+};
+
+use crate::transitive::Hop;
+
+/// The input to the [`crate::serde_with`] macro, ie. `pub mod name = A -> B -> C`.
+pub struct SerdeWithInput {
+    vis: Visibility,
+    /// Whether the conversions are done through [`TryFrom`].
+    fallible: bool,
+    name: Ident,
+    /// Types in the path, starting with the field type.
+    /// Guaranteed to contain at least two types.
+    types: Vec<Type>,
+}
+
+impl Parse for SerdeWithInput {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let vis = input.parse()?;
+        let fallible = input.parse::<Option<Token![try]>>()?.is_some();
+        input.parse::<Token![mod]>()?;
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+
+        let error_span = input.span();
+        let types =
+            Punctuated::<Type, Token![->]>::parse_separated_nonempty_with(input, parse_type)?;
+
+        if types.len() < 2 {
+            return Err(SynError::new(error_span, "at least two types required"));
+        }
+
+        if !input.is_empty() {
+            return Err(input.error("expected '->' or end of input"));
+        }
+
+        let output = Self {
+            vis,
+            fallible,
+            name,
+            types: types.into_iter().collect(),
+        };
+
+        Ok(output)
+    }
+}
+
+/// Parses a plain type of the path, rejecting function steps, hop markers and types that do not
+/// name a single concrete type.
+///
+/// ```compile_fail
+/// struct Timeout(u64);
+/// struct Secs(u64);
+///
+/// // fails to compile, hop markers are not allowed
+/// transitive::serde_with!(mod secs = Timeout -> plain Secs -> u64);
+/// ```
+fn parse_type(input: ParseStream) -> SynResult<Type> {
+    if input.peek(Token![fn]) || input.peek(Token![try]) {
+        let msg = "function steps not allowed in 'serde_with!', the path is converted both ways";
+        return Err(input.error(msg));
+    }
+
+    let span = input.span();
+    if !matches!(input.parse::<Hop>()?, Hop::Trait) {
+        let msg = "hop markers not allowed in 'serde_with!', the path is converted both ways";
+        return Err(SynError::new(span, msg));
+    }
+
+    match input.parse()? {
+        ty @ (Type::ImplTrait(_) | Type::Infer(_) | Type::Never(_) | Type::Macro(_)) => {
+            Err(SynError::new_spanned(ty, "expected a concrete type"))
+        }
+        ty => Ok(ty),
+    }
+}
+
+impl ToTokens for SerdeWithInput {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let vis = &self.vis;
+        let name = &self.name;
+        let (field, path) = self.types.split_first().expect("at least two types");
+        let wire = path.last().expect("at least two types");
+
+        // Serialization goes through the path and deserialization goes back through it.
+        let ser_stmts = path.iter().map(|ty| match self.fallible {
+            true => quote! {
+                let val: #ty = core::convert::TryFrom::try_from(val)
+                    .map_err(::serde::ser::Error::custom)?;
+            },
+            false => quote! {let val: #ty = core::convert::From::from(val);},
+        });
+
+        let de_stmts = self
+            .types
+            .iter()
+            .rev()
+            .skip(1)
+            .map(|ty| match self.fallible {
+                true => quote! {
+                    let val: #ty = core::convert::TryFrom::try_from(val)
+                        .map_err(::serde::de::Error::custom)?;
+                },
+                false => quote! {let val: #ty = core::convert::From::from(val);},
+            });
+
+        let expanded = quote! {
+            #vis mod #name {
+                use super::*;
+
+                pub fn serialize<__S>(val: &#field, serializer: __S) -> core::result::Result<__S::Ok, __S::Error>
+                where
+                    __S: ::serde::Serializer,
+                {
+                    let val = core::clone::Clone::clone(val);
+                    #(#ser_stmts)*
+                    ::serde::Serialize::serialize(&val, serializer)
+                }
+
+                pub fn deserialize<'de, __D>(deserializer: __D) -> core::result::Result<#field, __D::Error>
+                where
+                    __D: ::serde::Deserializer<'de>,
+                {
+                    let val: #wire = ::serde::Deserialize::deserialize(deserializer)?;
+                    #(#de_stmts)*
+                    core::result::Result::Ok(val)
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
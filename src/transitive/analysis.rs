@@ -0,0 +1,371 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Error as SynError, Ident, Type, TypePath};
+
+use crate::transitive::{
+    conv_trait::ConvTrait,
+    item::{Hop, Step},
+    lift::Direction,
+    Errors,
+};
+
+/// A borrowed view of the types and hops of a path, used to analyze the conversions it relies on.
+pub struct PathView<'a> {
+    pub direction: Direction,
+    pub first_type: &'a Type,
+    pub intermediate_types: &'a [Type],
+    pub last_type: &'a Type,
+    pub hops: &'a [Hop],
+    pub steps: &'a [Vec<Step>],
+    pub trailing_steps: &'a [Step],
+    /// The trait called at the hops of the path, if they go through a conversion trait at all,
+    /// unlike the hops of `deref_to` and `cast_ref` paths.
+    pub conv: Option<&'a ConvTrait>,
+    /// Whether the path implements a conversion trait that other paths can call. Paths generating
+    /// inherent methods or other traits do not, so they can neither conflict with other paths nor
+    /// be part of a cycle, but their types still get checked.
+    pub generates: bool,
+}
+
+impl PathView<'_> {
+    /// Stands for the derived type in the conversions of the path.
+    const DERIVED: &'static str = "Self";
+
+    fn types(&self) -> impl Iterator<Item = &Type> {
+        // A single type path holds the type as both the first and the last one.
+        std::iter::once(self.first_type)
+            .chain(self.intermediate_types)
+            .chain(std::iter::once(self.last_type))
+            .take(self.hops.len())
+    }
+
+    /// The types written in the path, for spanning errors. The field type at the end of a path
+    /// placed on a variant or a field is not part of them.
+    fn spanned(&self) -> TokenStream {
+        let first = self.first_type;
+        let last = match self.hops.last() {
+            Some(Hop::Wrap(_)) => self.intermediate_types.last().unwrap_or(first),
+            _ => self.last_type,
+        };
+
+        quote!(#first #last)
+    }
+
+    /// Makes sure that neither the derived type nor any other type appears twice in a part of the
+    /// path joined by hops alone. A step may lead anywhere, so the types on either side of it are
+    /// not compared, ie. `into(u64, fn double, u64, B)` is allowed.
+    fn check_repeated(&self, derived: &Ident, errors: &mut Errors) {
+        // The chain of types the value goes through, the derived type being `None`, each along
+        // with whether steps precede it.
+        let types = self
+            .types()
+            .zip(self.steps)
+            .map(|(ty, steps)| (Some(ty), !steps.is_empty()));
+        let chain = match self.direction {
+            Direction::From => types
+                .chain(std::iter::once((None, !self.trailing_steps.is_empty())))
+                .collect::<Vec<_>>(),
+            Direction::Into => std::iter::once((None, false)).chain(types).collect(),
+        };
+
+        let mut seen: Vec<(String, Option<&Type>)> = Vec::new();
+
+        for (ty, stepped) in chain {
+            if stepped {
+                seen.clear();
+            }
+
+            let key = match ty {
+                Some(ty) if !is_derived(ty, derived) => type_key(ty),
+                _ => Self::DERIVED.to_owned(),
+            };
+
+            let Some((_, prev)) = seen.iter().find(|(k, _)| *k == key) else {
+                seen.push((key, ty));
+                continue;
+            };
+
+            // Point at the type written in the path, since the derived type itself is implicit.
+            let spanned = ty.or(*prev).expect("the derived type is in the chain once");
+            let msg = match key == Self::DERIVED {
+                true => "derived type not allowed in its own path",
+                false => "type repeated in path",
+            };
+            errors.push(SynError::new_spanned(spanned, msg));
+        }
+    }
+
+    /// The conversion implemented by the path.
+    fn generated(&self) -> Conversion {
+        let conv = self
+            .conv
+            .expect("paths generating conversions go through a trait");
+        let class = TraitClass::of(conv);
+        match self.direction {
+            Direction::From => Conversion::new(class, &type_key(self.first_type), Self::DERIVED),
+            Direction::Into => Conversion::new(class, Self::DERIVED, &type_key(self.last_type)),
+        }
+    }
+
+    /// The conversions called at the hops of the path. Hops right after a step are skipped, since
+    /// their source type is unknown.
+    fn used(&self) -> Vec<Conversion> {
+        let types = self.types().map(type_key).collect::<Vec<_>>();
+        let derived = Self::DERIVED.to_owned();
+
+        // The chain of types the value goes through, along with the hop and steps preceding each
+        // conversion.
+        let (chain, hops): (Vec<_>, Vec<_>) = match self.direction {
+            Direction::From => {
+                let preceding = self.steps[1..]
+                    .iter()
+                    .map(Vec::as_slice)
+                    .chain(std::iter::once(self.trailing_steps));
+                let chain = types.iter().chain(std::iter::once(&derived));
+                (chain.collect(), self.hops.iter().zip(preceding).collect())
+            }
+            Direction::Into => {
+                let preceding = self.steps.iter().map(Vec::as_slice);
+                let chain = std::iter::once(&derived).chain(&types);
+                (chain.collect(), self.hops.iter().zip(preceding).collect())
+            }
+        };
+
+        chain
+            .windows(2)
+            .zip(hops)
+            .filter_map(|(pair, (hop, steps))| {
+                let class = match hop {
+                    _ if !steps.is_empty() => return None,
+                    Hop::Trait => TraitClass::of(self.conv?),
+                    Hop::Plain => TraitClass::Std,
+                    Hop::Coerce | Hop::Wrap(_) => return None,
+                };
+
+                Some(Conversion::new(class, pair[0], pair[1]))
+            })
+            .collect()
+    }
+}
+
+/// Checks the paths of a derived type for repeated types, for paths generating the same
+/// conversion and for generated impls that end up calling themselves, which would recurse
+/// infinitely at runtime. Every finding gets recorded, so that independent conflicts and cycles
+/// all get reported at once.
+///
+/// The checks are purely syntactic: types are compared by their tokens, only normalizing a leading
+/// `::`, and the derived type is only recognized by its name or a lone `Self`. Aliases, imports
+/// under other names and `Self` nested in other types therefore go unnoticed here, the compiler
+/// catching whatever they lead to instead.
+///
+/// ```compile_fail
+/// use transitive::Transitive;
+///
+/// #[derive(Transitive)]
+/// #[transitive(from(C, B))] // fails to compile, calls `From<B> for A`
+/// #[transitive(from(B, C))] // fails to compile, calls `From<C> for A`
+/// struct A;
+/// struct B;
+/// struct C;
+/// ```
+///
+/// ```compile_fail
+/// use transitive::Transitive;
+///
+/// #[derive(Transitive)]
+/// #[transitive(from(D, C, B))]
+/// #[transitive(from(D, E, B))] // fails to compile, `From<D> for A` is already generated
+/// struct A;
+/// struct B;
+/// struct C;
+/// struct D;
+/// struct E;
+/// ```
+///
+/// ```compile_fail
+/// use transitive::Transitive;
+///
+/// #[derive(Transitive)]
+/// #[transitive(into(B, C, B, D))] // fails to compile, `B` is repeated
+/// struct A;
+/// struct B;
+/// struct C;
+/// struct D;
+/// ```
+///
+/// ```compile_fail
+/// use transitive::Transitive;
+///
+/// #[derive(Transitive)]
+/// #[transitive(serde_into(B, C, B, D))] // fails to compile, `B` is repeated
+/// struct A;
+/// struct B;
+/// struct C;
+/// struct D;
+/// ```
+///
+/// ```compile_fail
+/// use transitive::Transitive;
+///
+/// #[derive(Transitive)]
+/// #[transitive(from_str(B, C, B))] // fails to compile, `B` is repeated
+/// struct A;
+/// struct B;
+/// struct C;
+/// ```
+pub fn check_paths(views: &[PathView], derived: &Ident, errors: &mut Errors) {
+    for view in views {
+        view.check_repeated(derived, errors);
+    }
+
+    let mut index = HashMap::with_capacity(views.len());
+
+    for (i, view) in views.iter().enumerate().filter(|(_, view)| view.generates) {
+        match index.entry(view.generated()) {
+            Entry::Vacant(entry) => {
+                entry.insert(i);
+            }
+            Entry::Occupied(entry) => {
+                let msg = "conflicting path, only one path is possible between the same source \
+                           and target types";
+                let mut error = SynError::new_spanned(views[i].spanned(), msg);
+                let msg = "first path between the same source and target types";
+                error.combine(SynError::new_spanned(views[*entry.get()].spanned(), msg));
+                errors.push(error);
+            }
+        }
+    }
+
+    // Each path depends on the paths generating the conversions it calls.
+    let deps = views
+        .iter()
+        .map(|view| {
+            view.used()
+                .iter()
+                .filter_map(|conv| index.get(conv).copied())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut state = vec![Visit::New; views.len()];
+    let mut stack = Vec::new();
+
+    for start in 0..views.len() {
+        if let Some(cycle) = find_cycle(start, &deps, &mut state, &mut stack) {
+            let msg = "path is part of a cycle of generated impls calling each other";
+            let mut cycle_errors = cycle
+                .into_iter()
+                .map(|i| SynError::new_spanned(views[i].spanned(), msg));
+
+            let mut error = cycle_errors.next().expect("cycles are not empty");
+            cycle_errors.for_each(|e| error.combine(e));
+            errors.push(error);
+
+            // Leave the paths of the cycle out of the search for further, independent cycles.
+            for node in stack.drain(..) {
+                state[node] = Visit::Done;
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Visit {
+    New,
+    InProgress,
+    Done,
+}
+
+/// Depth first search returning the paths of the first cycle reachable from `node`.
+fn find_cycle(
+    node: usize,
+    deps: &[Vec<usize>],
+    state: &mut [Visit],
+    stack: &mut Vec<usize>,
+) -> Option<Vec<usize>> {
+    match state[node] {
+        Visit::Done => return None,
+        Visit::InProgress => {
+            let pos = stack
+                .iter()
+                .position(|n| *n == node)
+                .expect("node on stack");
+            return Some(stack[pos..].to_vec());
+        }
+        Visit::New => (),
+    }
+
+    state[node] = Visit::InProgress;
+    stack.push(node);
+
+    for &dep in &deps[node] {
+        if let Some(cycle) = find_cycle(dep, deps, state, stack) {
+            return Some(cycle);
+        }
+    }
+
+    stack.pop();
+    state[node] = Visit::Done;
+    None
+}
+
+/// A conversion between two types, either generated or called by a path.
+#[derive(PartialEq, Eq, Hash)]
+struct Conversion {
+    class: TraitClass,
+    source: String,
+    target: String,
+}
+
+impl Conversion {
+    fn new(class: TraitClass, source: &str, target: &str) -> Self {
+        Self {
+            class,
+            source: source.to_owned(),
+            target: target.to_owned(),
+        }
+    }
+}
+
+/// The trait a conversion goes through.
+#[derive(PartialEq, Eq, Hash)]
+enum TraitClass {
+    /// [`From`] and [`TryFrom`], treated alike since [`TryFrom`] is implemented for every [`From`]
+    /// conversion.
+    Std,
+    /// A custom trait, named by its path.
+    Custom(String),
+}
+
+impl TraitClass {
+    fn of(conv: &ConvTrait) -> Self {
+        let path = conv.path().to_token_stream().to_string();
+        let std_paths = [ConvTrait::from(), ConvTrait::try_from()]
+            .map(|conv| conv.path().to_token_stream().to_string());
+
+        match std_paths.contains(&path) {
+            true => Self::Std,
+            false => Self::Custom(path),
+        }
+    }
+}
+
+/// Checks whether the type refers to the derived type, either by name or through `Self`.
+fn is_derived(ty: &Type, derived: &Ident) -> bool {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return false;
+    };
+
+    path.segments.len() == 1
+        && (path.segments[0].ident == *derived || path.segments[0].ident == "Self")
+}
+
+/// Syntactic key of a type, normalizing whitespace and leading `::`.
+fn type_key(ty: &Type) -> String {
+    let key = ty.to_token_stream().to_string();
+    key.strip_prefix(":: ")
+        .map(ToOwned::to_owned)
+        .unwrap_or(key)
+}
@@ -0,0 +1,177 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token::Paren,
+    Attribute, Data, Error as SynError, Ident, Result as SynResult, Token, Type,
+};
+
+use crate::transitive::{
+    analysis::PathView,
+    item::{Hop, HopList, PathTypes},
+    lift::Direction,
+    method_ident, TokenizablePath,
+};
+
+/// Path corresponding to a [`#[transitive(cast_ref(..))`] path.
+///
+/// The derived type gets cast to the first type in the path, which it must be
+/// `#[repr(transparent)]` over. The remaining layers are cast through the safe methods generated
+/// by the rest of the path on the first type, so the only unsafe cast is the one checked by this
+/// derive.
+///
+/// ```compile_fail
+/// use transitive::Transitive;
+///
+/// #[derive(Transitive)]
+/// #[transitive(cast_ref(u64))] // fails to compile, not `#[repr(transparent)]`
+/// struct Id(u64);
+/// ```
+pub struct CastRef {
+    /// Types the reference gets cast through, ie. `B` and `C` in `#[transitive(cast_ref(B, C))]`.
+    types: HopList,
+    /// Name of the generated reference method, derived from the last type.
+    ref_method: Ident,
+    /// Name of the generated slice method, derived from the last type.
+    slice_method: Ident,
+}
+
+impl CastRef {
+    const REPR: &'static str = "repr";
+    const TRANSPARENT: &'static str = "transparent";
+
+    /// Makes sure the derived type is `#[repr(transparent)]` over the first type in the path, as
+    /// otherwise the generated casts would be unsound. The field type is compared with the first
+    /// type by its tokens, so both must be written the same way, ie. an alias of the field type
+    /// gets rejected.
+    pub fn check_input(&self, attrs: &[Attribute], data: &Data) -> SynResult<()> {
+        let first = &self.types.first_type;
+        let msg =
+            "'cast_ref' requires the derived type to be `#[repr(transparent)]` over the first type";
+
+        let mut transparent = false;
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident(Self::REPR)) {
+            attr.parse_nested_meta(|meta| {
+                transparent |= meta.path.is_ident(Self::TRANSPARENT);
+                // Skip the arguments of other representations, ie. `align(8)`.
+                if meta.input.peek(Paren) {
+                    meta.input.parse::<TokenTree>()?;
+                }
+                Ok(())
+            })?;
+        }
+
+        let Data::Struct(data) = data else {
+            return Err(SynError::new_spanned(first, msg));
+        };
+
+        // Any other field of a transparent struct is guaranteed by the compiler to be a 1-ZST.
+        let first_str = first.to_token_stream().to_string();
+        let has_field = data
+            .fields
+            .iter()
+            .any(|f| f.ty.to_token_stream().to_string() == first_str);
+
+        match transparent && has_field {
+            true => Ok(()),
+            false => Err(SynError::new_spanned(first, msg)),
+        }
+    }
+
+    /// The names of the generated methods.
+    pub fn methods(&self) -> Vec<Ident> {
+        vec![self.ref_method.clone(), self.slice_method.clone()]
+    }
+
+    /// A view of the types of the path, which goes through no conversion trait.
+    pub fn view(&self) -> PathView<'_> {
+        PathView {
+            direction: Direction::Into,
+            first_type: &self.types.first_type,
+            intermediate_types: &self.types.intermediate_types,
+            last_type: &self.types.last_type,
+            hops: &self.types.hops,
+            steps: &self.types.steps,
+            trailing_steps: &self.types.trailing_steps,
+            conv: None,
+            generates: false,
+        }
+    }
+}
+
+impl Parse for CastRef {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let error_span = input.span();
+        let mut types = PathTypes::default();
+
+        for ty in Punctuated::<Type, Token![,]>::parse_terminated(input)? {
+            types.push_type(Hop::Trait, ty);
+        }
+
+        let types = types.finish(error_span, true)?;
+        let ref_method = method_ident("cast_ref", &types.last_type)?;
+        let slice_method = method_ident("cast_slice", &types.last_type)?;
+
+        let output = Self {
+            types,
+            ref_method,
+            slice_method,
+        };
+
+        Ok(output)
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &CastRef> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let vis = self.vis;
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let first = &self.path.types.first_type;
+        let last = &self.path.types.last_type;
+        let ref_method = &self.path.ref_method;
+        let slice_method = &self.path.slice_method;
+
+        // The remaining layers are cast by the same path on the first type, ie. `cast_ref(C, D)`
+        // on `B` for `cast_ref(B, C, D)`.
+        let (ref_stmt, slice_stmt) = match self.path.types.hops.len() == 1 {
+            true => Default::default(),
+            false => (
+                quote! {let val: &#last = <#first>::#ref_method(val);},
+                quote! {let val: &[#last] = <#first>::#slice_method(val);},
+            ),
+        };
+
+        let expanded = quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #vis fn #ref_method(&self) -> &#last {
+                    // SAFETY: The derive checked that `Self` is `#[repr(transparent)]` over the
+                    // first type.
+                    let val: &#first = unsafe { &*(self as *const Self).cast::<#first>() };
+                    #ref_stmt
+                    val
+                }
+
+                #vis fn #slice_method(val: &[Self]) -> &[#last] {
+                    // SAFETY: The derive checked that `Self` is `#[repr(transparent)]` over the
+                    // first type, so both slices have the same layout.
+                    let val: &[#first] = unsafe {
+                        core::slice::from_raw_parts(val.as_ptr().cast::<#first>(), val.len())
+                    };
+                    #slice_stmt
+                    val
+                }
+            }
+
+            impl #impl_generics core::convert::AsRef<#last> for #name #ty_generics #where_clause {
+                fn as_ref(&self) -> &#last {
+                    Self::#ref_method(self)
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
@@ -0,0 +1,352 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote, Error as SynError, GenericParam, Generics, Ident, Path, Result as SynResult,
+    Token, Type, TypeReference,
+};
+
+use crate::transitive::closest_name;
+
+/// The conversion trait called at each hop of a path, [`From`] and [`TryFrom`] unless overridden
+/// with a `trait = MyTrait, method = my_method` option.
+#[derive(Clone)]
+pub struct ConvTrait {
+    /// Path of the trait, ie. `crate::proto::FromProto`.
+    path: Path,
+    /// The conversion method of the trait, ie. `from_proto`.
+    method: Ident,
+    /// The name of the associated error type of a fallible trait, ie. `Error`.
+    error_name: Option<Ident>,
+    /// The context passed by reference to each hop, ie. `ctx: &Registry`.
+    context: Option<Context>,
+    /// Whether the conversion method is an `async fn`.
+    asyncness: bool,
+    /// Whether the trait path or the context type name the generics of the derived type.
+    #[cfg_attr(not(feature = "diagnostics"), allow(dead_code))]
+    generic: bool,
+}
+
+impl ConvTrait {
+    /// The [`From`] trait.
+    pub fn from() -> Self {
+        Self {
+            path: parse_quote!(core::convert::From),
+            method: parse_quote!(from),
+            error_name: None,
+            context: None,
+            asyncness: false,
+            generic: false,
+        }
+    }
+
+    /// The [`TryFrom`] trait.
+    pub fn try_from() -> Self {
+        Self {
+            path: parse_quote!(core::convert::TryFrom),
+            method: parse_quote!(try_from),
+            error_name: None,
+            context: None,
+            asyncness: false,
+            generic: false,
+        }
+    }
+
+    /// The `AsyncTryFrom` trait of the `transitive-async` companion crate.
+    pub fn async_try_from() -> Self {
+        Self {
+            path: parse_quote!(::transitive_async::AsyncTryFrom),
+            method: parse_quote!(async_try_from),
+            error_name: None,
+            context: None,
+            asyncness: true,
+            generic: false,
+        }
+    }
+
+    /// Turns the trait into one with an `async fn` conversion method.
+    pub fn into_async(self) -> Self {
+        Self {
+            asyncness: true,
+            ..self
+        }
+    }
+
+    /// Path of the trait.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Name of the trait, without its module path.
+    #[cfg_attr(not(feature = "diagnostics"), allow(dead_code))]
+    pub fn name(&self) -> &Ident {
+        &self
+            .path
+            .segments
+            .last()
+            .expect("trait paths are not empty")
+            .ident
+    }
+
+    /// Name of the conversion method.
+    pub fn method(&self) -> &Ident {
+        &self.method
+    }
+
+    /// Whether the trait or its context type name the generics of the derived type, which items
+    /// nested in the generated code cannot refer to.
+    #[cfg_attr(not(feature = "diagnostics"), allow(dead_code))]
+    pub fn is_generic(&self) -> bool {
+        self.generic
+    }
+
+    /// The `async` keyword of the conversion method, if any.
+    pub fn asyncness(&self) -> Option<Token![async]> {
+        self.asyncness.then(Default::default)
+    }
+
+    /// The trait converting from the given source type, with the context type as the second
+    /// generic argument if there is one.
+    pub fn bound(&self, source: impl ToTokens) -> TokenStream {
+        let path = &self.path;
+
+        match &self.context {
+            Some(Context { ty, .. }) => {
+                let elem = &ty.elem;
+                quote!(#path<#source, #elem>)
+            }
+            None => quote!(#path<#source>),
+        }
+    }
+
+    /// The parameters of the conversion method, ie. `val: D, ctx: &Registry`.
+    pub fn params(&self, source: impl ToTokens) -> TokenStream {
+        match &self.context {
+            Some(Context { ident, ty }) => quote!(val: #source, #ident: #ty),
+            None => quote!(val: #source),
+        }
+    }
+
+    /// Expression converting `val` to the target type through the trait, threading the context if
+    /// there is one. The expression is spanned at the type of the hop, so that a missing impl gets
+    /// reported there. The source type is inferred when unknown, ie. after a step.
+    pub fn call(
+        &self,
+        source: Option<impl ToTokens>,
+        target: impl ToTokens,
+        span: Span,
+    ) -> TokenStream {
+        let bound = match source {
+            Some(source) => self.bound(source),
+            None => self.bound(quote!(_)),
+        };
+        let method = &self.method;
+
+        let call = match &self.context {
+            // A mutable context is reborrowed, so that it does not get moved into the first hop.
+            Some(Context { ident, ty }) if ty.mutability.is_some() => {
+                quote_spanned!(span=> <#target as #bound>::#method(val, &mut *#ident))
+            }
+            Some(Context { ident, .. }) => {
+                quote_spanned!(span=> <#target as #bound>::#method(val, #ident))
+            }
+            None => quote_spanned!(span=> <#target as #bound>::#method(val)),
+        };
+
+        match self.asyncness {
+            true => quote_spanned!(span=> #call.await),
+            false => call,
+        }
+    }
+
+    /// Name of the associated error type, `Error` unless overridden.
+    pub fn error_name(&self) -> TokenStream {
+        match &self.error_name {
+            Some(name) => quote!(#name),
+            None => quote!(Error),
+        }
+    }
+
+    /// Returns an error if the associated error type name was given for an infallible path.
+    pub fn reject_error_name(&self) -> SynResult<()> {
+        match &self.error_name {
+            Some(name) => Err(SynError::new_spanned(
+                name,
+                "'error_name' not allowed for infallible paths",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Builds the custom trait from the options of an attribute, if any were given.
+    pub fn from_options(options: Vec<TraitOption>, generics: &Generics) -> SynResult<Option<Self>> {
+        let mut path = None;
+        let mut method = None;
+        let mut error_name = None;
+        let mut context = None;
+
+        for option in options {
+            match option {
+                TraitOption::Trait(key, _) if path.is_some() => {
+                    let msg = "'trait' not allowed multiple times";
+                    return Err(SynError::new_spanned(key, msg));
+                }
+                TraitOption::Trait(key, value) => path = Some((key, value)),
+                TraitOption::Method(key, _) if method.is_some() => {
+                    let msg = "'method' not allowed multiple times";
+                    return Err(SynError::new_spanned(key, msg));
+                }
+                TraitOption::Method(key, value) => method = Some((key, value)),
+                TraitOption::ErrorName(key, _) if error_name.is_some() => {
+                    let msg = "'error_name' not allowed multiple times";
+                    return Err(SynError::new_spanned(key, msg));
+                }
+                TraitOption::ErrorName(key, value) => error_name = Some((key, value)),
+                TraitOption::Context(key, _) if context.is_some() => {
+                    let msg = "'with_context' not allowed multiple times";
+                    return Err(SynError::new_spanned(key, msg));
+                }
+                TraitOption::Context(key, value) => context = Some((key, value)),
+            }
+        }
+
+        let (path, method) = match (path, method) {
+            (Some((_, path)), Some((_, method))) => (path, method),
+            (Some((key, _)), None) => {
+                let msg = "'method' required along with 'trait'";
+                return Err(SynError::new_spanned(key, msg));
+            }
+            (None, Some((key, _))) => {
+                let msg = "'trait' required along with 'method'";
+                return Err(SynError::new_spanned(key, msg));
+            }
+            (None, None) => match (error_name, context) {
+                (Some((key, _)), _) => {
+                    let msg = "'trait' required along with 'error_name'";
+                    return Err(SynError::new_spanned(key, msg));
+                }
+                (_, Some((key, _))) => {
+                    let msg = "'trait' required along with 'with_context'";
+                    return Err(SynError::new_spanned(key, msg));
+                }
+                (None, None) => return Ok(None),
+            },
+        };
+
+        let context = context.map(|(_, context)| context);
+
+        let mut tokens = path.to_token_stream();
+        if let Some(Context { ty, .. }) = &context {
+            ty.elem.to_tokens(&mut tokens);
+        }
+
+        let output = Self {
+            generic: names_generics(tokens, generics),
+            path,
+            method,
+            error_name: error_name.map(|(_, name)| name),
+            context,
+            asyncness: false,
+        };
+
+        Ok(Some(output))
+    }
+}
+
+/// Whether the tokens name any of the generic parameters, or `Self`.
+fn names_generics(tokens: TokenStream, generics: &Generics) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) if ident == "Self" => true,
+        TokenTree::Ident(ident) => generics.params.iter().any(|param| match param {
+            GenericParam::Type(param) => param.ident == ident,
+            GenericParam::Const(param) => param.ident == ident,
+            GenericParam::Lifetime(param) => param.lifetime.ident == ident,
+        }),
+        TokenTree::Group(group) => names_generics(group.stream(), generics),
+        _ => false,
+    })
+}
+
+/// An option of a `#[transitive(..)]` attribute overriding the conversion trait of its paths.
+pub enum TraitOption {
+    /// `trait = crate::proto::FromProto`
+    Trait(Token![trait], Path),
+    /// `method = from_proto`
+    Method(Ident, Ident),
+    /// `error_name = Error`
+    ErrorName(Ident, Ident),
+    /// `with_context(ctx: &Registry)`
+    Context(Ident, Context),
+}
+
+impl TraitOption {
+    const METHOD: &'static str = "method";
+    const ERROR_NAME: &'static str = "error_name";
+    const WITH_CONTEXT: &'static str = "with_context";
+
+    /// All the option names, used to suggest the closest one to an unknown option.
+    const NAMES: [&'static str; 4] = ["trait", Self::METHOD, Self::ERROR_NAME, Self::WITH_CONTEXT];
+
+    /// Whether the input starts with an option instead of a path.
+    pub fn peek(input: ParseStream) -> bool {
+        if input.peek(Token![trait]) || (input.peek(Ident) && input.peek2(Token![=])) {
+            return true;
+        }
+
+        let fork = input.fork();
+        fork.parse::<Ident>()
+            .is_ok_and(|ident| ident == Self::WITH_CONTEXT)
+    }
+}
+
+impl Parse for TraitOption {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        if input.peek(Token![trait]) {
+            let key = input.parse()?;
+            input.parse::<Token![=]>()?;
+            return input.parse().map(|path| Self::Trait(key, path));
+        }
+
+        let key = input.parse::<Ident>()?;
+
+        if key == Self::WITH_CONTEXT {
+            let content;
+            syn::parenthesized!(content in input);
+            return content.parse().map(|context| Self::Context(key, context));
+        }
+
+        input.parse::<Token![=]>()?;
+
+        match key {
+            key if key == Self::METHOD => input.parse().map(|method| Self::Method(key, method)),
+            key if key == Self::ERROR_NAME => input.parse().map(|name| Self::ErrorName(key, name)),
+            key => {
+                let msg = match closest_name(&key.to_string(), Self::NAMES) {
+                    Some(name) => format!("unknown option, did you mean '{name}'?"),
+                    None => "unknown option".to_owned(),
+                };
+                Err(SynError::new(key.span(), msg))
+            }
+        }
+    }
+}
+
+/// The context of a [`ConvTrait`], ie. `ctx: &Registry`.
+#[derive(Clone)]
+pub struct Context {
+    ident: Ident,
+    ty: TypeReference,
+}
+
+impl Parse for Context {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = match input.parse()? {
+            Type::Reference(ty) => ty,
+            ty => return Err(SynError::new_spanned(ty, "expected a reference type")),
+        };
+
+        Ok(Self { ident, ty })
+    }
+}
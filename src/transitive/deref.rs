@@ -0,0 +1,194 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Error as SynError, Ident, Result as SynResult, Token, Type,
+};
+
+use crate::transitive::{
+    analysis::PathView,
+    item::{Hop, HopList, PathTypes},
+    lift::Direction,
+    method_ident, parse_all, Errors, TokenizablePath,
+};
+
+/// Path corresponding to a [`#[transitive(deref_to(..))`] path.
+pub struct DerefTo {
+    /// Types in the deref chain, ie. `B`, `C` and `D` in `#[transitive(deref_to(B, C, D))]`. Every
+    /// hop goes through [`core::ops::Deref`], so there are no steps.
+    types: HopList,
+    /// Name of the generated method, derived from the last type.
+    method: Ident,
+    /// Whether to also generate the `_mut` method through [`core::ops::DerefMut`].
+    mutable: bool,
+    /// Whether to also implement [`AsRef`] for the last type.
+    as_ref: bool,
+}
+
+impl Parse for DerefTo {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let error_span = input.span();
+
+        let mut types = PathTypes::default();
+        let mut mutable = false;
+        let mut as_ref = false;
+
+        // Carry on past the faulty items so that all of their errors get reported at once.
+        let mut errors = Errors::default();
+
+        for item in parse_all::<Item>(input)? {
+            let Some(item) = errors.ok(item) else {
+                continue;
+            };
+
+            match item {
+                Item::Type(ty) if mutable || as_ref => {
+                    let msg = "types not allowed after flags";
+                    errors.push(SynError::new_spanned(ty, msg));
+                }
+                Item::Type(ty) => types.push_type(Hop::Trait, ty),
+                Item::Mut(flag) if mutable => {
+                    let msg = "'mut' not allowed multiple times";
+                    errors.push(SynError::new_spanned(flag, msg));
+                }
+                Item::Mut(_) => mutable = true,
+                Item::AsRef(flag) if as_ref => {
+                    let msg = "'as_ref' not allowed multiple times";
+                    errors.push(SynError::new_spanned(flag, msg));
+                }
+                Item::AsRef(_) => as_ref = true,
+            }
+        }
+
+        errors.finish()?;
+
+        let types = types.finish(error_span, false)?;
+        let method = method_ident("deref_to", &types.last_type)?;
+
+        let output = Self {
+            types,
+            method,
+            mutable,
+            as_ref,
+        };
+
+        Ok(output)
+    }
+}
+
+impl DerefTo {
+    /// The names of the generated methods.
+    pub fn methods(&self) -> Vec<Ident> {
+        let mut methods = vec![self.method.clone()];
+        if self.mutable {
+            methods.push(format_ident!("{}_mut", self.method));
+        }
+        methods
+    }
+
+    /// A view of the types of the path, which goes through no conversion trait.
+    pub fn view(&self) -> PathView<'_> {
+        PathView {
+            direction: Direction::Into,
+            first_type: &self.types.first_type,
+            intermediate_types: &self.types.intermediate_types,
+            last_type: &self.types.last_type,
+            hops: &self.types.hops,
+            steps: &self.types.steps,
+            trailing_steps: &self.types.trailing_steps,
+            conv: None,
+            generates: false,
+        }
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &DerefTo> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let vis = self.vis;
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let HopList {
+            first_type,
+            intermediate_types,
+            last_type: last,
+            ..
+        } = &self.path.types;
+        let method = &self.path.method;
+
+        let types = || {
+            std::iter::once(first_type)
+                .chain(intermediate_types)
+                .chain(std::iter::once(last))
+        };
+
+        let stmts = types().map(|ty| quote! {let val: &#ty = core::ops::Deref::deref(val);});
+
+        let mut methods = quote! {
+            #vis fn #method(&self) -> &#last {
+                let val = self;
+                #(#stmts)*
+                val
+            }
+        };
+
+        if self.path.mutable {
+            let method_mut = format_ident!("{}_mut", method);
+            let stmts =
+                types().map(|ty| quote! {let val: &mut #ty = core::ops::DerefMut::deref_mut(val);});
+
+            methods.extend(quote! {
+                #vis fn #method_mut(&mut self) -> &mut #last {
+                    let val = self;
+                    #(#stmts)*
+                    val
+                }
+            });
+        }
+
+        tokens.extend(quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #methods
+            }
+        });
+
+        if self.path.as_ref {
+            tokens.extend(quote! {
+                impl #impl_generics core::convert::AsRef<#last> for #name #ty_generics #where_clause {
+                    fn as_ref(&self) -> &#last {
+                        Self::#method(self)
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// An item in the parameters list of a [`DerefTo`] path.
+enum Item {
+    Type(Type),
+    Mut(Token![mut]),
+    AsRef(Ident),
+}
+
+impl Item {
+    const AS_REF: &'static str = "as_ref";
+}
+
+impl Parse for Item {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        if input.peek(Token![mut]) {
+            return input.parse().map(Self::Mut);
+        }
+
+        let fork = input.fork();
+        // A flag is a lone ident, so it must be followed by a comma or nothing at all
+        let is_as_ref = fork
+            .parse::<Ident>()
+            .is_ok_and(|ident| ident == Self::AS_REF && (fork.is_empty() || fork.peek(Token![,])));
+
+        match is_as_ref {
+            true => input.parse().map(Self::AsRef),
+            false => input.parse().map(Self::Type),
+        }
+    }
+}
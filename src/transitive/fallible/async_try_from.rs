@@ -0,0 +1,47 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{
+    parse::{Parse, ParseStream},
+    Result as SynResult, Type,
+};
+
+use super::TryTransitionFrom;
+use crate::transitive::{analysis::PathView, conv_trait::ConvTrait, TokenizablePath};
+
+/// Path corresponding to a [`#[transitive(async_try_from(..))`] path.
+///
+/// Behaves just like [`TryTransitionFrom`], except that it implements the `AsyncTryFrom` trait of
+/// the `transitive-async` crate, or the async trait given through the `trait` and `method`
+/// options of the attribute, and awaits every hop that is not marked as `plain`.
+pub struct AsyncTryTransitionFrom(TryTransitionFrom);
+
+impl Parse for AsyncTryTransitionFrom {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let mut path = TryTransitionFrom::parse(input)?;
+        path.set_trait(&ConvTrait::async_try_from())?;
+        Ok(Self(path))
+    }
+}
+
+impl AsyncTryTransitionFrom {
+    /// A view of the types and hops of the path.
+    pub fn view(&self) -> PathView<'_> {
+        self.0.view()
+    }
+
+    /// The first and last types of the path, if they need to be checked for being distinct.
+    pub fn distinct_types(&self) -> Option<(&Type, &Type)> {
+        self.0.distinct_types()
+    }
+
+    /// Overrides the async trait called at each hop of the path.
+    pub fn set_trait(&mut self, conv: &ConvTrait) -> SynResult<()> {
+        self.0.set_trait(&conv.clone().into_async())
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &AsyncTryTransitionFrom> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        TokenizablePath::new(self.vis, self.ident, self.generics, &self.path.0).to_tokens(tokens);
+    }
+}
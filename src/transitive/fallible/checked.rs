@@ -0,0 +1,178 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Error as SynError, Ident, Result as SynResult, Type,
+};
+
+use super::FallibleTypeList;
+use crate::transitive::{
+    analysis::PathView,
+    distinct_types_eval,
+    item::{Hop, Step},
+    lift::{Direction, Lift},
+    method_ident, TokenizablePath,
+};
+
+/// Path corresponding to a [`#[transitive(checked_from(..))`] path.
+///
+/// Like [`super::TryTransitionFrom`], but generates an inherent method returning an [`Option`],
+/// discarding the error of every hop.
+pub struct CheckedFrom(FallibleTypeList);
+
+impl Parse for CheckedFrom {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let mut list = parse_checked_list(input)?;
+        list.shift_hops(Hop::Trait)?;
+        Step::reject(&list.steps[0], "steps not allowed before the first type")?;
+        Ok(Self(list))
+    }
+}
+
+impl CheckedFrom {
+    /// A view of the types and hops of the path, which generates no conversion trait impl.
+    pub fn view(&self) -> PathView<'_> {
+        PathView {
+            generates: false,
+            ..self.0.view(Direction::From)
+        }
+    }
+
+    /// The first and last types of the path, if they need to be checked for being distinct.
+    pub fn distinct_types(&self) -> Option<(&Type, &Type)> {
+        self.0.distinct_types()
+    }
+
+    /// The name of the generated method, derived from the first type.
+    pub fn method(&self) -> SynResult<Ident> {
+        method_ident("checked_from", &self.0.first_type)
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &CheckedFrom> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let vis = self.vis;
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let list = &self.path.0;
+        let first = &list.first_type;
+
+        let method = match self.path.method() {
+            Ok(method) => method,
+            Err(e) => return tokens.extend(e.to_compile_error()),
+        };
+
+        let stmts = list.stmts_from(quote!(.ok()?), checked_step);
+
+        let types_check = list
+            .distinct_types()
+            .map(|_| distinct_types_eval(name, &ty_generics));
+
+        let expanded = quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #vis fn #method(val: #first) -> core::option::Option<Self> {
+                    #types_check
+                    #(#stmts)*
+                    core::option::Option::Some(val)
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
+
+/// Path corresponding to a [`#[transitive(checked_into(..))`] path.
+///
+/// Like [`super::TryTransitionInto`], but generates an inherent method returning an [`Option`],
+/// discarding the error of every hop.
+pub struct CheckedInto(FallibleTypeList);
+
+impl Parse for CheckedInto {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let list = parse_checked_list(input)?;
+        Step::reject(
+            &list.trailing_steps,
+            "steps not allowed after the last type",
+        )?;
+        Ok(Self(list))
+    }
+}
+
+impl CheckedInto {
+    /// A view of the types and hops of the path, which generates no conversion trait impl.
+    pub fn view(&self) -> PathView<'_> {
+        PathView {
+            generates: false,
+            ..self.0.view(Direction::Into)
+        }
+    }
+
+    /// The first and last types of the path, if they need to be checked for being distinct.
+    pub fn distinct_types(&self) -> Option<(&Type, &Type)> {
+        self.0.distinct_types()
+    }
+
+    /// The name of the generated method, derived from the last type.
+    pub fn method(&self) -> SynResult<Ident> {
+        method_ident("checked_into", &self.0.last_type)
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &CheckedInto> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let vis = self.vis;
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let list = &self.path.0;
+        let last = &list.last_type;
+
+        let method = match self.path.method() {
+            Ok(method) => method,
+            Err(e) => return tokens.extend(e.to_compile_error()),
+        };
+
+        let stmts = list.stmts_into(quote!(Self), quote!(.ok()?), checked_step);
+
+        let types_check = list
+            .distinct_types()
+            .map(|_| distinct_types_eval(name, &ty_generics));
+
+        let expanded = quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #vis fn #method(self) -> core::option::Option<#last> {
+                    #types_check
+                    let val = self;
+                    #(#stmts)*
+                    core::option::Option::Some(val)
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
+
+/// Parses a [`FallibleTypeList`] that has no use for an error type.
+fn parse_checked_list(input: ParseStream) -> SynResult<FallibleTypeList> {
+    let list = FallibleTypeList::parse(input)?;
+    list.reject_fallback()?;
+
+    if let Some(error) = &list.error {
+        let msg = "'error' not allowed for checked conversions";
+        return Err(SynError::new_spanned(error, msg));
+    }
+
+    Lift::reject(list.lift.as_ref())?;
+    Ok(list)
+}
+
+/// Statement applying a step to `val`, discarding its error if the step is fallible.
+fn checked_step(step: &Step) -> TokenStream {
+    let call = step.call();
+
+    match step.try_token() {
+        Some(_) => quote! {let val = #call.ok()?;},
+        None => quote! {let val = #call;},
+    }
+}
@@ -0,0 +1,57 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Result as SynResult,
+};
+
+use super::TryTransitionFrom;
+use crate::transitive::{analysis::PathView, lift::Lift, TokenizablePath};
+
+/// Path corresponding to a [`#[transitive(from_str(..))`] path.
+///
+/// Behaves just like [`TryTransitionFrom`], except that the first type in the path gets parsed
+/// from a string slice.
+pub struct TransitionFromStr(TryTransitionFrom);
+
+impl Parse for TransitionFromStr {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let path = TryTransitionFrom::parse_single(input)?;
+        Lift::reject(path.lift())?;
+        Ok(Self(path))
+    }
+}
+
+impl TransitionFromStr {
+    /// A view of the types and hops of the path, which generates no conversion trait impl.
+    pub fn view(&self) -> PathView<'_> {
+        PathView {
+            generates: false,
+            ..self.0.view()
+        }
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &TransitionFromStr> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let first = self.path.0.first_type();
+        let stmts = self.path.0.stmts();
+        let error = self.path.0.error();
+
+        let expanded = quote! {
+            impl #impl_generics core::str::FromStr for #name #ty_generics #where_clause {
+                type Err = #error;
+
+                fn from_str(val: &str) -> core::result::Result<Self, Self::Err> {
+                    let val: #first = core::str::FromStr::from_str(val)?;
+                    #(#stmts)*
+                    core::result::Result::Ok(val)
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
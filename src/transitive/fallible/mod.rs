@@ -1,14 +1,36 @@
+mod async_try_from;
+mod checked;
+mod from_str;
+mod or_else;
+mod try_default;
 mod try_from;
 mod try_into;
+mod try_serde;
 
+pub use async_try_from::AsyncTryTransitionFrom;
+pub use checked::{CheckedFrom, CheckedInto};
+pub use from_str::TransitionFromStr;
+pub use or_else::{TransitionFromOrDefault, TransitionFromOrElse};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::{
-    parse::{discouraged::Speculative, Parse, ParseStream},
-    Error as SynError, Ident, Result as SynResult, Token, Type,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    Error as SynError, ExprPath, Result as SynResult, Type,
 };
+pub use try_default::TryTransitionDefault;
 pub use try_from::TryTransitionFrom;
 pub use try_into::TryTransitionInto;
+pub use try_serde::{TrySerdeFrom, TrySerdeInto};
 
-use crate::transitive::AtLeastTwoTypes;
+use crate::transitive::{
+    analysis::PathView,
+    conv_trait::ConvTrait,
+    distinct_types,
+    item::{Hop, HopList, HopPosition, PathItem, PathTypes, Step},
+    lift::{Direction, Lift},
+    parse_all, Errors,
+};
 
 /// A path list that may contain a custom error type.
 struct FallibleTypeList {
@@ -22,81 +44,323 @@ struct FallibleTypeList {
     /// `#[transitive(try_from(A, B, C, D, E))]`
     last_type: Type,
     error: Option<Type>,
+    /// The conversion of each type in the list, in the same order.
+    hops: Vec<Hop>,
+    /// The function steps preceding each type in the list, in the same order.
+    steps: Vec<Vec<Step>>,
+    /// The function steps following the last type in the list.
+    trailing_steps: Vec<Step>,
+    lift: Option<Lift>,
+    /// The function handling a failed conversion, only allowed in `from_or_else` paths.
+    fallback: Option<ExprPath>,
+    /// Whether the first and last types get checked for being distinct.
+    distinct_check: bool,
+    /// The trait called at each hop of the path.
+    conv: ConvTrait,
 }
 
 impl Parse for FallibleTypeList {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        let AtLeastTwoTypes {
-            first_type,
-            second_type: mut last_type,
-            remaining,
-        } = AtLeastTwoTypes::parse(input)?;
+        Self::parse_with_tail(input, None, false)
+    }
+}
 
-        let mut intermediate_types = Vec::with_capacity(remaining.len());
+impl FallibleTypeList {
+    /// Parses the list, appending the given type after the last one in the input. A `single` list
+    /// may consist of one type only, converted straight to the derived type.
+    fn parse_with_tail(
+        input: ParseStream,
+        tail: Option<(Hop, Type)>,
+        single: bool,
+    ) -> SynResult<Self> {
+        let error_span = input.span();
+
+        let mut types = PathTypes::default();
         let mut error = None;
+        let mut lift = None;
+        let mut fallback = None;
+        let mut distinct_check = true;
+
+        // Carry on past the faulty items so that all of their errors get reported at once.
+        let mut errors = Errors::default();
 
-        for attr in remaining {
-            match attr {
-                Item::Type(ty) if error.is_some() => {
+        for item in parse_all::<PathItem>(input)? {
+            let Some(item) = errors.ok(item) else {
+                continue;
+            };
+
+            match item {
+                PathItem::Type(_, ty) if error.is_some() => {
                     let msg = "types not allowed after 'error'";
-                    return Err(SynError::new_spanned(ty, msg));
+                    errors.push(SynError::new_spanned(ty, msg));
+                }
+                PathItem::Step(step) if error.is_some() => {
+                    let msg = "steps not allowed after 'error'";
+                    errors.push(SynError::new_spanned(step, msg));
+                }
+                PathItem::Type(_, ty) if lift.is_some() => {
+                    let msg = "types not allowed after 'lift'";
+                    errors.push(SynError::new_spanned(ty, msg));
                 }
                 // Just a regular type path in the conversion path
-                Item::Type(ty) => {
-                    intermediate_types.push(last_type);
-                    last_type = ty;
+                PathItem::Type(hop, ty) => types.push_type(hop, ty),
+                PathItem::Step(step) if lift.is_some() => {
+                    let msg = "steps not allowed after 'lift'";
+                    errors.push(SynError::new_spanned(step, msg));
                 }
-                Item::Error(err) if error.is_some() => {
+                PathItem::Step(step) => types.push_step(step),
+                PathItem::Error(err) if error.is_some() => {
                     let msg = "'error' not allowed multiple times";
-                    return Err(SynError::new_spanned(err, msg));
+                    errors.push(SynError::new_spanned(err, msg));
                 }
                 // Custom error, but must check that it's a type path
-                Item::Error(err) => error = Some(err),
+                PathItem::Error(err) => error = Some(err),
+                PathItem::Lift(l) if lift.is_some() => {
+                    let msg = "'lift' not allowed multiple times";
+                    errors.push(SynError::new_spanned(l, msg));
+                }
+                PathItem::Lift(l) => lift = Some(l),
+                PathItem::NoDistinctCheck(flag) if !distinct_check => {
+                    let msg = "'no_distinct_check' not allowed multiple times";
+                    errors.push(SynError::new_spanned(flag, msg));
+                }
+                PathItem::NoDistinctCheck(_) => distinct_check = false,
+                PathItem::Ref(flag) => {
+                    let msg = "'ref' not allowed in fallible paths";
+                    errors.push(SynError::new_spanned(flag, msg));
+                }
+                PathItem::Fallback(f) if fallback.is_some() => {
+                    let msg = "'fallback' not allowed multiple times";
+                    errors.push(SynError::new_spanned(f, msg));
+                }
+                PathItem::Fallback(f) => fallback = Some(f),
             }
         }
 
+        errors.finish()?;
+
+        if let Some((hop, ty)) = tail {
+            types.push_type(hop, ty);
+        }
+
+        let HopList {
+            first_type,
+            intermediate_types,
+            last_type,
+            hops,
+            steps,
+            trailing_steps,
+        } = types.finish(error_span, single)?;
+
         let output = Self {
             first_type,
             intermediate_types,
             last_type,
             error,
+            hops,
+            steps,
+            trailing_steps,
+            lift,
+            fallback,
+            distinct_check,
+            conv: ConvTrait::try_from(),
         };
 
         Ok(output)
     }
-}
 
-/// An item in the parameters list of an attribute.
-enum Item {
-    Type(Type),
-    Error(Type),
-}
+    /// Moves the marker of each type onto the hop leaving the type before it, as a marker applies
+    /// to the hop into the marked type while the hops of paths converting from their first type
+    /// are kept along with their source type. The first type has no hop into it, and the hop into
+    /// the derived type becomes `last`.
+    fn shift_hops(&mut self, last: Hop) -> SynResult<()> {
+        if !matches!(self.hops.first(), Some(Hop::Trait)) {
+            let msg = "hop markers not allowed on the first type";
+            return Err(SynError::new_spanned(&self.first_type, msg));
+        }
 
-impl Parse for Item {
-    fn parse(input: ParseStream) -> SynResult<Self> {
-        let fork = input.fork();
-        // Parse the ident name and the equal sign after it
-        let res = fork
-            .parse::<Ident>()
-            .and_then(|ident| fork.parse::<Token![=]>().map(|_| ident));
-
-        match res {
-            // We got an `error = MyType` argument
-            Ok(path) if path == "error" => {
-                input.advance_to(&fork);
-                input.parse().map(Self::Error)
+        self.hops.remove(0);
+        self.hops.push(last);
+        Ok(())
+    }
+
+    /// Returns an error if a `fallback` is present, which only `from_or_else` paths support.
+    fn reject_fallback(&self) -> SynResult<()> {
+        match &self.fallback {
+            Some(fallback) => Err(SynError::new_spanned(
+                fallback,
+                "'fallback' not allowed here",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// The first and last types of the list, if they need to be checked for being distinct.
+    fn distinct_types(&self) -> Option<(&Type, &Type)> {
+        distinct_types(&self.first_type, &self.last_type, self.distinct_check)
+    }
+
+    /// A view of the types and hops of the list, in the given direction.
+    fn view(&self, direction: Direction) -> PathView<'_> {
+        PathView {
+            direction,
+            first_type: &self.first_type,
+            intermediate_types: &self.intermediate_types,
+            last_type: &self.last_type,
+            hops: &self.hops,
+            steps: &self.steps,
+            trailing_steps: &self.trailing_steps,
+            conv: Some(&self.conv),
+            generates: true,
+        }
+    }
+
+    /// Overrides the trait called at each hop of the path.
+    fn set_trait(&mut self, conv: &ConvTrait) -> SynResult<()> {
+        if let Some(lift) = &self.lift {
+            let msg = "'lift' not allowed with a custom trait";
+            return Err(SynError::new_spanned(lift, msg));
+        }
+
+        self.conv = conv.clone();
+        Ok(())
+    }
+
+    /// Statement converting `val` to the target type through the given hop, handling the error
+    /// through `propagate`, ie. `?`. The source type is inferred when unknown because of a
+    /// preceding step.
+    fn hop_stmt(
+        &self,
+        hop: &Hop,
+        source: Option<impl ToTokens>,
+        target: impl ToTokens,
+        propagate: TokenStream,
+        span: Span,
+    ) -> TokenStream {
+        let call = match hop {
+            Hop::Trait => self.conv.call(source, &target, span),
+            Hop::Plain => {
+                let source = source.map_or_else(|| quote!(_), |s| s.to_token_stream());
+                quote_spanned!(span=> <#target as core::convert::TryFrom<#source>>::try_from(val))
             }
-            // Try to parse anything else as a type in the path list
-            _ => input.parse().map(Self::Type),
+            // Coercions cannot fail, so there is no error to propagate.
+            Hop::Coerce => return quote! {let val: #target = val;},
+            Hop::Wrap(wrap) => return quote! {let val: #target = #wrap;},
+        };
+
+        quote_spanned! {span=> let val: #target = #call #propagate;}
+    }
+
+    /// Statements going from the first type in the list to the derived type, handling the error of
+    /// each hop through `propagate` and turning each function step into a statement with `step`.
+    fn stmts_from(
+        &self,
+        propagate: TokenStream,
+        step: impl Fn(&Step) -> TokenStream,
+    ) -> Vec<TokenStream> {
+        let (last_hop, hops) = self.hops.split_last().expect("at least two hops");
+        let sources = std::iter::once(&self.first_type).chain(&self.intermediate_types);
+        let targets = self
+            .intermediate_types
+            .iter()
+            .chain(std::iter::once(&self.last_type));
+
+        let mut stmts = Vec::new();
+
+        // The steps preceding a type get applied right before the hop converting to it.
+        let hops = sources.zip(targets).zip(hops).zip(&self.steps[1..]);
+        for (i, (((source, ty), hop), steps)) in hops.enumerate() {
+            let position = self.position(i, source);
+            let source = steps.is_empty().then_some(source);
+            stmts.extend(steps.iter().map(&step));
+            stmts.push(self.hop_check(hop, source, ty, position));
+            stmts.push(self.hop_stmt(hop, source, ty, propagate.clone(), position.span));
+        }
+
+        let last = &self.last_type;
+        let position = self.position(self.hops.len() - 1, last);
+        let source = self.trailing_steps.is_empty().then_some(last);
+        stmts.extend(self.trailing_steps.iter().map(&step));
+        stmts.push(self.hop_check(last_hop, source, quote!(Self), position));
+        stmts.push(self.hop_stmt(last_hop, source, quote!(Self), propagate, position.span));
+        stmts
+    }
+
+    /// Statements going from the derived type to the last type in the list, handling the error of
+    /// each hop through `propagate` and turning each function step into a statement with `step`.
+    fn stmts_into(
+        &self,
+        derived: impl ToTokens,
+        propagate: TokenStream,
+        step: impl Fn(&Step) -> TokenStream,
+    ) -> Vec<TokenStream> {
+        let (last_hop, hops) = self.hops.split_last().expect("at least two hops");
+        let (last_steps, steps) = self.steps.split_last().expect("at least two types");
+        let targets = std::iter::once(&self.first_type).chain(&self.intermediate_types);
+        let sources = std::iter::once(derived.to_token_stream())
+            .chain(targets.clone().map(ToTokens::to_token_stream));
+
+        let mut stmts = Vec::new();
+
+        // The steps preceding a type get applied right before the hop converting to it.
+        let hops = sources.zip(targets).zip(hops).zip(steps);
+        for (i, (((source, ty), hop), steps)) in hops.enumerate() {
+            let position = self.position(i, ty);
+            let source = steps.is_empty().then_some(source);
+            stmts.extend(steps.iter().map(&step));
+            stmts.push(self.hop_check(hop, source.as_ref(), ty, position));
+            stmts.push(self.hop_stmt(hop, source, ty, propagate.clone(), position.span));
         }
+
+        let last = &self.last_type;
+        let source = self.intermediate_types.last().unwrap_or(&self.first_type);
+        let source = last_steps.is_empty().then_some(source);
+        let position = self.position(self.hops.len() - 1, last);
+        stmts.extend(last_steps.iter().map(&step));
+        stmts.push(self.hop_check(last_hop, source, last, position));
+        stmts.push(self.hop_stmt(last_hop, source, last, propagate, position.span));
+        stmts
     }
-}
 
-impl From<Item> for Option<Type> {
-    fn from(value: Item) -> Self {
-        match value {
-            Item::Type(ty) => Some(ty),
-            Item::Error(_) => None,
+    /// The position of the hop at the given zero based index, belonging to the given type.
+    fn position(&self, index: usize, ty: &Type) -> HopPosition {
+        HopPosition {
+            index: index + 1,
+            count: self.hops.len(),
+            // Only the location is taken from the type, so that the generated code is still seen
+            // as coming from the macro.
+            span: Span::call_site().located_at(ty.span()),
+        }
+    }
+
+    /// Statement checking the impl called at the given hop, unless its source type is unknown
+    /// because of a preceding step.
+    fn hop_check(
+        &self,
+        hop: &Hop,
+        source: Option<impl ToTokens>,
+        target: impl ToTokens,
+        position: HopPosition,
+    ) -> TokenStream {
+        let conv = match (hop, &source) {
+            (Hop::Trait, Some(_)) => self.conv.clone(),
+            (Hop::Plain, Some(_)) => ConvTrait::try_from(),
+            _ => return TokenStream::new(),
+        };
+
+        position.check(&conv, source, target)
+    }
+
+    /// The error type of the given hop converting from `source` to `target`.
+    fn hop_error(&self, hop: &Hop, target: impl ToTokens, source: &Type) -> TokenStream {
+        match hop {
+            Hop::Trait => {
+                let bound = self.conv.bound(source);
+                let error_name = self.conv.error_name();
+                quote!(<#target as #bound>::#error_name)
+            }
+            Hop::Plain => quote!(<#target as core::convert::TryFrom<#source>>::Error),
+            Hop::Coerce | Hop::Wrap(_) => quote!(core::convert::Infallible),
         }
     }
 }
@@ -0,0 +1,115 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Error as SynError, ExprPath, Generics, Ident, Result as SynResult, Type,
+};
+
+use super::TryTransitionFrom;
+use crate::transitive::{analysis::PathView, distinct_types_eval, lift::Lift, TokenizablePath};
+
+/// Path corresponding to a [`#[transitive(from_or_default(..))`] path.
+///
+/// Behaves just like [`TryTransitionFrom`], except that it generates a [`From`] impl which falls
+/// back to the [`Default`] value of the derived type if the conversion fails.
+pub struct TransitionFromOrDefault(TryTransitionFrom);
+
+impl Parse for TransitionFromOrDefault {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let path = TryTransitionFrom::parse(input)?;
+        Lift::reject(path.lift())?;
+        Ok(Self(path))
+    }
+}
+
+impl TransitionFromOrDefault {
+    /// A view of the types and hops of the path.
+    pub fn view(&self) -> PathView<'_> {
+        self.0.view()
+    }
+
+    /// The first and last types of the path, if they need to be checked for being distinct.
+    pub fn distinct_types(&self) -> Option<(&Type, &Type)> {
+        self.0.distinct_types()
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &TransitionFromOrDefault> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let fallback = quote!(|_| core::default::Default::default());
+        fallback_impl(self.ident, self.generics, &self.path.0, fallback).to_tokens(tokens);
+    }
+}
+
+/// Path corresponding to a [`#[transitive(from_or_else(.., fallback = my::fallback_fn))`] path.
+///
+/// Behaves just like [`TryTransitionFrom`], except that it generates a [`From`] impl which passes
+/// the error to the fallback function if the conversion fails.
+pub struct TransitionFromOrElse {
+    path: TryTransitionFrom,
+    fallback: ExprPath,
+}
+
+impl TransitionFromOrElse {
+    /// A view of the types and hops of the path.
+    pub fn view(&self) -> PathView<'_> {
+        self.path.view()
+    }
+
+    /// The first and last types of the path, if they need to be checked for being distinct.
+    pub fn distinct_types(&self) -> Option<(&Type, &Type)> {
+        self.path.distinct_types()
+    }
+}
+
+impl Parse for TransitionFromOrElse {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let error_span = input.span();
+        let (path, fallback) = TryTransitionFrom::parse_with_fallback(input)?;
+        Lift::reject(path.lift())?;
+
+        let Some(fallback) = fallback else {
+            return Err(SynError::new(error_span, "'fallback' argument required"));
+        };
+
+        Ok(Self { path, fallback })
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &TransitionFromOrElse> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let fallback = self.path.fallback.to_token_stream();
+        fallback_impl(self.ident, self.generics, &self.path.path, fallback).to_tokens(tokens);
+    }
+}
+
+/// Generates the [`From`] impl running the fallible path and handing its error to `fallback`.
+fn fallback_impl(
+    name: &Ident,
+    generics: &Generics,
+    path: &TryTransitionFrom,
+    fallback: TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let first = path.first_type();
+    let stmts = path.stmts();
+    let error = path.error();
+
+    let types_check = path
+        .distinct_types()
+        .map(|_| distinct_types_eval(name, &ty_generics));
+
+    quote! {
+        impl #impl_generics core::convert::From<#first> for #name #ty_generics #where_clause {
+            fn from(val: #first) -> Self {
+                #types_check
+                let res: core::result::Result<Self, #error> = (|| {
+                    #(#stmts)*
+                    core::result::Result::Ok(val)
+                })();
+
+                res.unwrap_or_else(#fallback)
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Result as SynResult,
+};
+
+use super::TryTransitionFrom;
+use crate::transitive::{analysis::PathView, lift::Lift, TokenizablePath};
+
+/// Path corresponding to a [`#[transitive(try_default_via(..))`] path.
+///
+/// Behaves just like [`TryTransitionFrom`], except that the first type in the path is obtained
+/// through its [`Default`] impl. Since [`Default`] is infallible, a failed conversion panics.
+pub struct TryTransitionDefault(TryTransitionFrom);
+
+impl Parse for TryTransitionDefault {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let path = TryTransitionFrom::parse_single(input)?;
+        Lift::reject(path.lift())?;
+        Ok(Self(path))
+    }
+}
+
+impl TryTransitionDefault {
+    /// A view of the types and hops of the path, which generates no conversion trait impl.
+    pub fn view(&self) -> PathView<'_> {
+        PathView {
+            generates: false,
+            ..self.0.view()
+        }
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &TryTransitionDefault> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let first = self.path.0.first_type();
+        let stmts = self.path.0.stmts();
+        let error = self.path.0.error();
+
+        let expanded = quote! {
+            impl #impl_generics core::default::Default for #name #ty_generics #where_clause {
+                fn default() -> Self {
+                    let res: core::result::Result<Self, #error> = (|| {
+                        let val: #first = core::default::Default::default();
+                        #(#stmts)*
+                        core::result::Result::Ok(val)
+                    })();
+
+                    match res {
+                        core::result::Result::Ok(val) => val,
+                        core::result::Result::Err(_) => ::core::panic!(::core::concat!(
+                            "failed to convert the default `",
+                            ::core::stringify!(#first),
+                            "` into `",
+                            ::core::stringify!(#name),
+                            "`"
+                        )),
+                    }
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
@@ -2,18 +2,134 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
-    Result as SynResult,
+    ExprPath, Result as SynResult, Type,
 };
 
 use super::FallibleTypeList;
-use crate::transitive::{distinct_types_check, TokenizablePath};
+use crate::transitive::{
+    analysis::PathView,
+    conv_trait::ConvTrait,
+    distinct_types_eval,
+    item::{Hop, Step},
+    lift::{Direction, Lift, LiftedPath},
+    TokenizablePath,
+};
 
 /// Path corresponding to a [`#[transitive(try_from(..))`] path.
 pub struct TryTransitionFrom(FallibleTypeList);
 
 impl Parse for TryTransitionFrom {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        FallibleTypeList::parse(input).map(Self)
+        FallibleTypeList::parse(input).and_then(|list| Self::new(list, Hop::Trait))
+    }
+}
+
+impl TryTransitionFrom {
+    /// Builds the path from its list, ending with the `last` hop into the derived type.
+    fn new(mut list: FallibleTypeList, last: Hop) -> SynResult<Self> {
+        list.reject_fallback()?;
+        list.shift_hops(last)?;
+        Step::reject(&list.steps[0], "steps not allowed before the first type")?;
+
+        // The source type of the last hop is unknown, so its error type cannot be named.
+        if list.error.is_none() {
+            let (msg, steps) = match list.hops.last() {
+                Some(Hop::Wrap(_)) => (
+                    "steps not allowed right before the field type without a custom 'error'",
+                    list.steps.last().expect("at least two types"),
+                ),
+                _ => (
+                    "steps not allowed right before the derived type without a custom 'error'",
+                    &list.trailing_steps,
+                ),
+            };
+
+            Step::reject(steps, msg)?;
+        }
+
+        Ok(Self(list))
+    }
+
+    /// Parses a path along with its `fallback = ..` argument, if any.
+    pub(super) fn parse_with_fallback(input: ParseStream) -> SynResult<(Self, Option<ExprPath>)> {
+        let mut list = FallibleTypeList::parse(input)?;
+        let fallback = list.fallback.take();
+        Self::new(list, Hop::Trait).map(|path| (path, fallback))
+    }
+
+    /// Parses a path that may consist of a single type, converted straight to the derived type.
+    pub(super) fn parse_single(input: ParseStream) -> SynResult<Self> {
+        FallibleTypeList::parse_with_tail(input, None, true)
+            .and_then(|list| Self::new(list, Hop::Trait))
+    }
+
+    /// Parses a path placed on an enum variant or a struct field, which goes through the type of
+    /// the field and ends with the `wrap` expression building the derived type.
+    pub fn parse_wrapped(input: ParseStream, field: Type, wrap: TokenStream) -> SynResult<Self> {
+        FallibleTypeList::parse_with_tail(input, Some((Hop::Trait, field)), false)
+            .and_then(|list| Self::new(list, Hop::Wrap(wrap)))
+    }
+
+    /// Statements going from the first type in the path to the derived type.
+    pub(super) fn stmts(&self) -> Vec<TokenStream> {
+        self.0.stmts_from(quote!(?), Step::stmt)
+    }
+
+    /// The error type of the conversion, either custom or the one of the last hop.
+    pub(super) fn error(&self) -> TokenStream {
+        if let Some(error) = &self.0.error {
+            return quote!(#error);
+        }
+
+        match self.0.hops.split_last().expect("at least two hops") {
+            // Wrapping in a variant cannot fail, so use the error of the hop to the field type.
+            (Hop::Wrap(_), hops) => {
+                let source = self
+                    .0
+                    .intermediate_types
+                    .last()
+                    .unwrap_or(&self.0.first_type);
+                let hop = hops.last().expect("at least two hops");
+                self.0.hop_error(hop, &self.0.last_type, source)
+            }
+            (last_hop, _) => self.0.hop_error(last_hop, quote!(Self), &self.0.last_type),
+        }
+    }
+
+    /// The source type of the conversion.
+    pub(super) fn first_type(&self) -> &Type {
+        &self.0.first_type
+    }
+
+    /// The first and last types of the path, if they need to be checked for being distinct.
+    pub fn distinct_types(&self) -> Option<(&Type, &Type)> {
+        self.0.distinct_types()
+    }
+
+    /// A view of the types and hops of the path.
+    pub fn view(&self) -> PathView<'_> {
+        self.0.view(Direction::From)
+    }
+
+    /// Overrides the trait called at each hop of the path.
+    pub fn set_trait(&mut self, conv: &ConvTrait) -> SynResult<()> {
+        self.0.set_trait(conv)
+    }
+
+    /// The containers the conversion gets lifted to, if any.
+    pub(super) fn lift(&self) -> Option<&Lift> {
+        self.0.lift.as_ref()
+    }
+
+    /// The conversion of the path lifted to the containers of its `lift(..)` flag, if any.
+    pub fn lifted(&self) -> Option<LiftedPath<'_>> {
+        let lift = self.0.lift.as_ref()?;
+        Some(LiftedPath::new(
+            lift,
+            &self.0.first_type,
+            Direction::From,
+            true,
+        ))
     }
 }
 
@@ -22,41 +138,26 @@ impl ToTokens for TokenizablePath<'_, &TryTransitionFrom> {
         let name = self.ident;
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         let first = &self.path.0.first_type;
-        let last = &self.path.0.last_type;
 
-        let stmts = self
-            .path
-            .0
-            .intermediate_types
-            .iter()
-            .chain(std::iter::once(last))
-            .map(|ty| quote! {let val: #ty = core::convert::TryFrom::try_from(val)?;})
-            .chain(std::iter::once(
-                quote! {let val = core::convert::TryFrom::try_from(val)?;},
-            ));
-
-        let error = self
+        let bound = self.path.0.conv.bound(quote!(#first));
+        let params = self.path.0.conv.params(quote!(#first));
+        let method = self.path.0.conv.method();
+        let asyncness = self.path.0.conv.asyncness();
+        let error_name = self.path.0.conv.error_name();
+        let stmts = self.path.stmts();
+        let error = self.path.error();
+
+        let types_check = self
             .path
             .0
-            .error
-            .as_ref()
-            .map(|e| quote!(#e))
-            .unwrap_or_else(|| quote!(<Self as TryFrom<#last>>::Error));
-
-        let types_check = distinct_types_check(
-            first,
-            last,
-            name,
-            &impl_generics,
-            &ty_generics,
-            where_clause,
-        );
+            .distinct_types()
+            .map(|_| distinct_types_eval(name, &ty_generics));
 
         let expanded = quote! {
-            impl #impl_generics core::convert::TryFrom<#first> for #name #ty_generics #where_clause {
-                type Error = #error;
+            impl #impl_generics #bound for #name #ty_generics #where_clause {
+                type #error_name = #error;
 
-                fn try_from(val: #first) -> core::result::Result<Self, Self::Error> {
+                #asyncness fn #method(#params) -> core::result::Result<Self, Self::#error_name> {
                     #types_check
                     #(#stmts)*
                     Ok(val)
@@ -65,5 +166,9 @@ impl ToTokens for TokenizablePath<'_, &TryTransitionFrom> {
         };
 
         tokens.extend(expanded);
+
+        if let Some(lifted) = self.path.lifted() {
+            TokenizablePath::new(self.vis, name, self.generics, lifted).to_tokens(tokens);
+        }
     }
 }
@@ -2,18 +2,97 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
-    Result as SynResult,
+    Result as SynResult, Type,
 };
 
 use super::FallibleTypeList;
-use crate::transitive::{distinct_types_check, TokenizablePath};
+use crate::transitive::{
+    analysis::PathView,
+    conv_trait::ConvTrait,
+    distinct_types_eval,
+    item::Step,
+    lift::{Direction, Lift, LiftedPath},
+    TokenizablePath,
+};
 
 /// Path corresponding to a [`#[transitive(try_into(..))`] path.
 pub struct TryTransitionInto(FallibleTypeList);
 
 impl Parse for TryTransitionInto {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        FallibleTypeList::parse(input).map(Self)
+        let list = FallibleTypeList::parse(input)?;
+        list.reject_fallback()?;
+        Step::reject(
+            &list.trailing_steps,
+            "steps not allowed after the last type",
+        )?;
+
+        // The source type of the last hop is unknown, so its error type cannot be named.
+        if list.error.is_none() {
+            let msg = "steps not allowed right before the last type without a custom 'error'";
+            Step::reject(list.steps.last().into_iter().flatten(), msg)?;
+        }
+        Ok(Self(list))
+    }
+}
+
+impl TryTransitionInto {
+    /// Statements going from the derived type to the last type in the path.
+    pub(super) fn stmts(&self, derived: impl ToTokens) -> Vec<TokenStream> {
+        self.0.stmts_into(derived, quote!(?), Step::stmt)
+    }
+
+    /// The error type of the conversion, either custom or the one of the last hop.
+    pub(super) fn error(&self) -> TokenStream {
+        let last = &self.0.last_type;
+        let last_hop = self.0.hops.last().expect("at least two hops");
+        let second_last = self
+            .0
+            .intermediate_types
+            .last()
+            .unwrap_or(&self.0.first_type);
+
+        self.0
+            .error
+            .as_ref()
+            .map(|e| quote!(#e))
+            .unwrap_or_else(|| self.0.hop_error(last_hop, last, second_last))
+    }
+
+    /// The target type of the conversion.
+    pub(super) fn last_type(&self) -> &Type {
+        &self.0.last_type
+    }
+
+    /// The first and last types of the path, if they need to be checked for being distinct.
+    pub fn distinct_types(&self) -> Option<(&Type, &Type)> {
+        self.0.distinct_types()
+    }
+
+    /// A view of the types and hops of the path.
+    pub fn view(&self) -> PathView<'_> {
+        self.0.view(Direction::Into)
+    }
+
+    /// Overrides the trait called at each hop of the path.
+    pub fn set_trait(&mut self, conv: &ConvTrait) -> SynResult<()> {
+        self.0.set_trait(conv)
+    }
+
+    /// The containers the conversion gets lifted to, if any.
+    pub(super) fn lift(&self) -> Option<&Lift> {
+        self.0.lift.as_ref()
+    }
+
+    /// The conversion of the path lifted to the containers of its `lift(..)` flag, if any.
+    pub fn lifted(&self) -> Option<LiftedPath<'_>> {
+        let lift = self.0.lift.as_ref()?;
+        Some(LiftedPath::new(
+            lift,
+            &self.0.last_type,
+            Direction::Into,
+            true,
+        ))
     }
 }
 
@@ -21,44 +100,38 @@ impl ToTokens for TokenizablePath<'_, &TryTransitionInto> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let name = self.ident;
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
-        let first = &self.path.0.first_type;
         let last = &self.path.0.last_type;
-        let second_last = self.path.0.intermediate_types.last().unwrap_or(first);
 
-        let stmts = std::iter::once(first)
-            .chain(&self.path.0.intermediate_types)
-            .map(|ty| quote! {let val: #ty = core::convert::TryFrom::try_from(val)?;});
+        let bound = self.path.0.conv.bound(quote!(#name #ty_generics));
+        let params = self.path.0.conv.params(quote!(#name #ty_generics));
+        let method = self.path.0.conv.method();
+        let asyncness = self.path.0.conv.asyncness();
+        let error_name = self.path.0.conv.error_name();
+        let stmts = self.path.stmts(quote!(#name #ty_generics));
+        let error = self.path.error();
 
-        let error = self
+        let types_check = self
             .path
             .0
-            .error
-            .as_ref()
-            .map(|e| quote!(#e))
-            .unwrap_or_else(|| quote!(<#last as TryFrom<#second_last>>::Error));
-
-        let types_check = distinct_types_check(
-            first,
-            last,
-            name,
-            &impl_generics,
-            &ty_generics,
-            where_clause,
-        );
+            .distinct_types()
+            .map(|_| distinct_types_eval(name, &ty_generics));
 
         let expanded = quote! {
-            impl #impl_generics core::convert::TryFrom<#name #ty_generics> for #last #where_clause {
-                type Error = #error;
+            impl #impl_generics #bound for #last #where_clause {
+                type #error_name = #error;
 
-                fn try_from(val: #name #ty_generics) -> core::result::Result<Self, Self::Error> {
+                #asyncness fn #method(#params) -> core::result::Result<Self, Self::#error_name> {
                     #types_check
                     #(#stmts)*
-                    let val = core::convert::TryFrom::try_from(val)?;
                     Ok(val)
                 }
             }
         };
 
         tokens.extend(expanded);
+
+        if let Some(lifted) = self.path.lifted() {
+            TokenizablePath::new(self.vis, name, self.generics, lifted).to_tokens(tokens);
+        }
     }
 }
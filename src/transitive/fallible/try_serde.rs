@@ -0,0 +1,119 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Result as SynResult,
+};
+
+use super::{TryTransitionFrom, TryTransitionInto};
+use crate::transitive::{analysis::PathView, de_generics, lift::Lift, TokenizablePath};
+
+/// Path corresponding to a [`#[transitive(try_serde_into(..))`] path.
+///
+/// Behaves just like [`TryTransitionInto`], except that the last type in the path gets
+/// serialized instead of being returned. Conversion errors are turned into serialization errors.
+pub struct TrySerdeInto(TryTransitionInto);
+
+impl Parse for TrySerdeInto {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let path = TryTransitionInto::parse(input)?;
+        Lift::reject(path.lift())?;
+        Ok(Self(path))
+    }
+}
+
+impl TrySerdeInto {
+    /// A view of the types and hops of the path, which generates no conversion trait impl.
+    pub fn view(&self) -> PathView<'_> {
+        PathView {
+            generates: false,
+            ..self.0.view()
+        }
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &TrySerdeInto> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let last = self.path.0.last_type();
+        let stmts = self.path.0.stmts(quote!(#name #ty_generics));
+        let error = self.path.0.error();
+
+        let expanded = quote! {
+            impl #impl_generics ::serde::Serialize for #name #ty_generics #where_clause {
+                fn serialize<__S>(&self, serializer: __S) -> core::result::Result<__S::Ok, __S::Error>
+                where
+                    __S: ::serde::Serializer,
+                {
+                    let res: core::result::Result<#last, #error> = (|| {
+                        let val = core::clone::Clone::clone(self);
+                        #(#stmts)*
+                        core::result::Result::Ok(val)
+                    })();
+
+                    let val = res.map_err(::serde::ser::Error::custom)?;
+                    ::serde::Serialize::serialize(&val, serializer)
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
+
+/// Path corresponding to a [`#[transitive(try_serde_from(..))`] path.
+///
+/// Behaves just like [`TryTransitionFrom`], except that the first type in the path gets
+/// deserialized instead of being an argument. Conversion errors are turned into deserialization
+/// errors.
+pub struct TrySerdeFrom(TryTransitionFrom);
+
+impl Parse for TrySerdeFrom {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let path = TryTransitionFrom::parse(input)?;
+        Lift::reject(path.lift())?;
+        Ok(Self(path))
+    }
+}
+
+impl TrySerdeFrom {
+    /// A view of the types and hops of the path, which generates no conversion trait impl.
+    pub fn view(&self) -> PathView<'_> {
+        PathView {
+            generates: false,
+            ..self.0.view()
+        }
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &TrySerdeFrom> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.ident;
+        let generics = de_generics(self.generics);
+        let (impl_generics, _, _) = generics.split_for_impl();
+        let (_, ty_generics, where_clause) = self.generics.split_for_impl();
+        let first = self.path.0.first_type();
+        let stmts = self.path.0.stmts();
+        let error = self.path.0.error();
+
+        let expanded = quote! {
+            impl #impl_generics ::serde::Deserialize<'de> for #name #ty_generics #where_clause {
+                fn deserialize<__D>(deserializer: __D) -> core::result::Result<Self, __D::Error>
+                where
+                    __D: ::serde::Deserializer<'de>,
+                {
+                    let val: #first = ::serde::Deserialize::deserialize(deserializer)?;
+                    let res: core::result::Result<Self, #error> = (|| {
+                        #(#stmts)*
+                        core::result::Result::Ok(val)
+                    })();
+
+                    res.map_err(::serde::de::Error::custom)
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
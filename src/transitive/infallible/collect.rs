@@ -0,0 +1,122 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Result as SynResult, Type,
+};
+
+use super::TypeList;
+use crate::transitive::{
+    analysis::PathView,
+    distinct_types_eval,
+    item::{Hop, Step},
+    lift::{Direction, Lift},
+    TokenizablePath,
+};
+
+/// Path corresponding to a [`#[transitive(collect_from(..))`] path.
+///
+/// Element-wise counterpart of [`super::TransitionFrom`], where the derived type is a collection
+/// of elements of the last type in the path.
+pub struct TransitionCollect(TypeList);
+
+impl Parse for TransitionCollect {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let mut list = TypeList::parse(input)?;
+        Lift::reject(list.lift.as_ref())?;
+        list.reject_ref()?;
+        let steps = list.steps.iter().flatten().chain(&list.trailing_steps);
+        Step::reject(steps, "steps not allowed here")?;
+
+        // The hop into the collection goes through its own impls, the one stored for it is unused.
+        list.shift_hops(Hop::Trait)?;
+
+        Ok(Self(list))
+    }
+}
+
+impl TransitionCollect {
+    /// Statements converting an element from the first type in the path to the last one.
+    fn stmts(&self) -> Vec<TokenStream> {
+        let list = &self.0;
+        let sources = std::iter::once(&list.first_type).chain(&list.intermediate_types);
+        let targets = list
+            .intermediate_types
+            .iter()
+            .chain(std::iter::once(&list.last_type));
+
+        let mut stmts = Vec::new();
+
+        for (i, ((source, ty), hop)) in sources.zip(targets).zip(&list.hops).enumerate() {
+            let position = list.position(i, source);
+            let call = list.call(hop, Some(source), ty, position.span);
+            stmts.push(list.hop_check(hop, Some(source), ty, position));
+            stmts.push(quote! {let val: #ty = #call;});
+        }
+
+        stmts
+    }
+
+    /// A view of the types and hops of the path, which generates no conversion trait impl.
+    pub fn view(&self) -> PathView<'_> {
+        PathView {
+            generates: false,
+            ..self.0.view(Direction::From)
+        }
+    }
+
+    /// The first and last types of the path, if they need to be checked for being distinct.
+    pub fn distinct_types(&self) -> Option<(&Type, &Type)> {
+        self.0.distinct_types()
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &TransitionCollect> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let first = &self.path.0.first_type;
+        let last = &self.path.0.last_type;
+
+        let stmts = self.path.stmts();
+
+        let map_fn = quote! {
+            |val: #first| {
+                #(#stmts)*
+                val
+            }
+        };
+
+        let types_check = self
+            .path
+            .0
+            .distinct_types()
+            .map(|_| distinct_types_eval(name, &ty_generics));
+
+        let expanded = quote! {
+            impl #impl_generics core::iter::FromIterator<#first> for #name #ty_generics #where_clause {
+                fn from_iter<__I>(iter: __I) -> Self
+                where
+                    __I: core::iter::IntoIterator<Item = #first>,
+                {
+                    #types_check
+                    let iter = core::iter::IntoIterator::into_iter(iter).map(#map_fn);
+                    <Self as core::iter::FromIterator<#last>>::from_iter(iter)
+                }
+            }
+
+            impl #impl_generics core::iter::Extend<#first> for #name #ty_generics #where_clause {
+                fn extend<__I>(&mut self, iter: __I)
+                where
+                    __I: core::iter::IntoIterator<Item = #first>,
+                {
+                    #types_check
+                    let iter = core::iter::IntoIterator::into_iter(iter).map(#map_fn);
+                    <Self as core::iter::Extend<#last>>::extend(self, iter)
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
@@ -0,0 +1,53 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Result as SynResult,
+};
+
+use super::TransitionFrom;
+use crate::transitive::{analysis::PathView, lift::Lift, TokenizablePath};
+
+/// Path corresponding to a [`#[transitive(default_via(..))`] path.
+///
+/// Behaves just like [`TransitionFrom`], except that the first type in the path is obtained
+/// through its [`Default`] impl.
+pub struct TransitionDefault(TransitionFrom);
+
+impl Parse for TransitionDefault {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let path = TransitionFrom::parse_single(input)?;
+        Lift::reject(path.lift())?;
+        Ok(Self(path))
+    }
+}
+
+impl TransitionDefault {
+    /// A view of the types and hops of the path, which generates no conversion trait impl.
+    pub fn view(&self) -> PathView<'_> {
+        PathView {
+            generates: false,
+            ..self.0.view()
+        }
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &TransitionDefault> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let first = self.path.0.first_type();
+        let stmts = self.path.0.stmts();
+
+        let expanded = quote! {
+            impl #impl_generics core::default::Default for #name #ty_generics #where_clause {
+                fn default() -> Self {
+                    let val: #first = core::default::Default::default();
+                    #(#stmts)*
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
@@ -2,18 +2,115 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
-    Result as SynResult,
+    Result as SynResult, Type,
 };
 
 use super::TypeList;
-use crate::transitive::{distinct_types_check, TokenizablePath};
+use crate::transitive::{
+    analysis::PathView,
+    conv_trait::ConvTrait,
+    distinct_types_eval,
+    item::{Hop, Step},
+    lift::{Direction, Lift, LiftedPath},
+    TokenizablePath,
+};
 
 /// Path corresponding to a [`#[transitive(from(..))`] path.
 pub struct TransitionFrom(TypeList);
 
 impl Parse for TransitionFrom {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        TypeList::parse(input).map(Self)
+        TypeList::parse(input).and_then(|list| Self::new(list, Hop::Trait))
+    }
+}
+
+impl TransitionFrom {
+    /// Builds the path from its list, ending with the `last` hop into the derived type.
+    pub(super) fn new(mut list: TypeList, last: Hop) -> SynResult<Self> {
+        list.reject_ref()?;
+        list.shift_hops(last)?;
+        Step::reject(&list.steps[0], "steps not allowed before the first type")?;
+        Ok(Self(list))
+    }
+
+    /// Parses a path that may consist of a single type, converted straight to the derived type.
+    pub(super) fn parse_single(input: ParseStream) -> SynResult<Self> {
+        TypeList::parse_with_tail(input, None, true).and_then(|list| Self::new(list, Hop::Trait))
+    }
+
+    /// Parses a path placed on an enum variant or a struct field, which goes through the type of
+    /// the field and ends with the `wrap` expression building the derived type.
+    pub fn parse_wrapped(input: ParseStream, field: Type, wrap: TokenStream) -> SynResult<Self> {
+        TypeList::parse_with_tail(input, Some((Hop::Trait, field)), false)
+            .and_then(|list| Self::new(list, Hop::Wrap(wrap)))
+    }
+
+    /// Statements going from the first type in the path to the derived type.
+    pub fn stmts(&self) -> Vec<TokenStream> {
+        let list = &self.0;
+        let (last_hop, hops) = list.hops.split_last().expect("at least two hops");
+        let sources = std::iter::once(&list.first_type).chain(&list.intermediate_types);
+        let targets = list
+            .intermediate_types
+            .iter()
+            .chain(std::iter::once(&list.last_type));
+
+        let mut stmts = Vec::new();
+
+        // The steps preceding a type get applied right before the hop converting to it.
+        let hops = sources.zip(targets).zip(hops).zip(&list.steps[1..]);
+        for (i, (((source, ty), hop), steps)) in hops.enumerate() {
+            let position = list.position(i, source);
+            let source = steps.is_empty().then_some(source);
+            let call = list.call(hop, source, ty, position.span);
+            stmts.extend(steps.iter().map(Step::stmt));
+            stmts.push(list.hop_check(hop, source, ty, position));
+            stmts.push(quote! {let val: #ty = #call;});
+        }
+
+        let last = &list.last_type;
+        let position = list.position(list.hops.len() - 1, last);
+        let source = list.trailing_steps.is_empty().then_some(last);
+        stmts.extend(list.trailing_steps.iter().map(Step::stmt));
+        stmts.push(list.hop_check(last_hop, source, quote!(Self), position));
+        stmts.push(list.call(last_hop, source, quote!(Self), position.span));
+        stmts
+    }
+
+    /// The source type of the conversion.
+    pub(super) fn first_type(&self) -> &Type {
+        &self.0.first_type
+    }
+
+    /// The first and last types of the path, if they need to be checked for being distinct.
+    pub fn distinct_types(&self) -> Option<(&Type, &Type)> {
+        self.0.distinct_types()
+    }
+
+    /// A view of the types and hops of the path.
+    pub fn view(&self) -> PathView<'_> {
+        self.0.view(Direction::From)
+    }
+
+    /// Overrides the trait called at each hop of the path.
+    pub fn set_trait(&mut self, conv: &ConvTrait) -> SynResult<()> {
+        self.0.set_trait(conv)
+    }
+
+    /// The containers the conversion gets lifted to, if any.
+    pub(super) fn lift(&self) -> Option<&Lift> {
+        self.0.lift.as_ref()
+    }
+
+    /// The conversion of the path lifted to the containers of its `lift(..)` flag, if any.
+    pub fn lifted(&self) -> Option<LiftedPath<'_>> {
+        let lift = self.0.lift.as_ref()?;
+        Some(LiftedPath::new(
+            lift,
+            &self.0.first_type,
+            Direction::From,
+            false,
+        ))
     }
 }
 
@@ -22,29 +119,21 @@ impl ToTokens for TokenizablePath<'_, &TransitionFrom> {
         let name = self.ident;
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         let first = &self.path.0.first_type;
-        let last = &self.path.0.last_type;
 
-        let stmts = self
+        let bound = self.path.0.conv.bound(first);
+        let method = self.path.0.conv.method();
+        let params = self.path.0.conv.params(first);
+        let stmts = self.path.stmts();
+
+        let types_check = self
             .path
             .0
-            .intermediate_types
-            .iter()
-            .chain(std::iter::once(last))
-            .map(|ty| quote! {let val: #ty = core::convert::From::from(val);})
-            .chain(std::iter::once(quote! {core::convert::From::from(val)}));
-
-        let types_check = distinct_types_check(
-            first,
-            last,
-            name,
-            &impl_generics,
-            &ty_generics,
-            where_clause,
-        );
+            .distinct_types()
+            .map(|_| distinct_types_eval(name, &ty_generics));
 
         let expanded = quote! {
-            impl #impl_generics core::convert::From<#first> for #name #ty_generics #where_clause {
-                fn from(val: #first) -> Self {
+            impl #impl_generics #bound for #name #ty_generics #where_clause {
+                fn #method(#params) -> Self {
                     #types_check
                     #(#stmts)*
                 }
@@ -52,5 +141,9 @@ impl ToTokens for TokenizablePath<'_, &TransitionFrom> {
         };
 
         tokens.extend(expanded);
+
+        if let Some(lifted) = self.path.lifted() {
+            TokenizablePath::new(self.vis, name, self.generics, lifted).to_tokens(tokens);
+        }
     }
 }
@@ -2,18 +2,133 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
-    Result as SynResult,
+    Result as SynResult, Token, Type,
 };
 
-use super::TypeList;
-use crate::transitive::{distinct_types_check, TokenizablePath};
+use super::{TransitionFrom, TypeList};
+use crate::transitive::{
+    analysis::PathView,
+    conv_trait::ConvTrait,
+    distinct_types_eval,
+    item::{Hop, Step},
+    lift::{Direction, Lift, LiftedPath},
+    TokenizablePath,
+};
 
 /// Path corresponding to a [`#[transitive(into(..))`] path.
 pub struct TransitionInto(TypeList);
 
 impl Parse for TransitionInto {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        TypeList::parse(input).map(Self)
+        let list = TypeList::parse(input)?;
+        list.reject_ref()?;
+        Self::new(list)
+    }
+}
+
+impl TransitionInto {
+    fn new(list: TypeList) -> SynResult<Self> {
+        Step::reject(
+            &list.trailing_steps,
+            "steps not allowed after the last type",
+        )?;
+        Ok(Self(list))
+    }
+
+    /// Parses the path of a `*_via` annotation, leading from the derived type to the target type.
+    /// It may consist of a single type and end with the `ref` flag.
+    pub fn parse_via(input: ParseStream) -> SynResult<Self> {
+        let list = TypeList::parse_with_tail(input, None, true)?;
+        Lift::reject(list.lift.as_ref())?;
+        Self::new(list)
+    }
+
+    /// Statements going from the derived type to the last type in the path.
+    pub fn stmts(&self, derived: impl ToTokens) -> Vec<TokenStream> {
+        let list = &self.0;
+        let (last_hop, hops) = list.hops.split_last().expect("at least two hops");
+        let (last_steps, steps) = list.steps.split_last().expect("at least two types");
+        let targets = std::iter::once(&list.first_type).chain(&list.intermediate_types);
+        let sources = std::iter::once(derived.to_token_stream())
+            .chain(targets.clone().map(ToTokens::to_token_stream));
+
+        let mut stmts = Vec::new();
+
+        // The steps preceding a type get applied right before the hop converting to it.
+        let hops = sources.zip(targets).zip(hops).zip(steps);
+        for (i, (((source, ty), hop), steps)) in hops.enumerate() {
+            let position = list.position(i, ty);
+            let source = steps.is_empty().then_some(source);
+            let call = list.call(hop, source.as_ref(), ty, position.span);
+            stmts.extend(steps.iter().map(Step::stmt));
+            stmts.push(list.hop_check(hop, source, ty, position));
+            stmts.push(quote! {let val: #ty = #call;});
+        }
+
+        let last = &list.last_type;
+        // A single type list goes straight from the derived type.
+        let source = match list.hops.len() {
+            1 => derived.to_token_stream(),
+            _ => {
+                let source = list.intermediate_types.last().unwrap_or(&list.first_type);
+                source.to_token_stream()
+            }
+        };
+        let source = last_steps.is_empty().then_some(source);
+        let position = list.position(list.hops.len() - 1, last);
+        stmts.extend(last_steps.iter().map(Step::stmt));
+        stmts.push(list.hop_check(last_hop, source.as_ref(), last, position));
+        stmts.push(list.call(last_hop, source, last, position.span));
+        stmts
+    }
+
+    /// The target type of the conversion.
+    pub fn last_type(&self) -> &Type {
+        &self.0.last_type
+    }
+
+    /// The `ref` flag of a `*_via` path, if present.
+    pub fn by_ref(&self) -> Option<&Token![ref]> {
+        self.0.by_ref.as_ref()
+    }
+
+    /// The path going back from the target type to the derived type, for `*_via` paths converting
+    /// both ways.
+    pub fn reversed(&self) -> SynResult<TransitionFrom> {
+        self.0
+            .reversed()
+            .and_then(|list| TransitionFrom::new(list, Hop::Trait))
+    }
+
+    /// The first and last types of the path, if they need to be checked for being distinct.
+    pub fn distinct_types(&self) -> Option<(&Type, &Type)> {
+        self.0.distinct_types()
+    }
+
+    /// A view of the types and hops of the path.
+    pub fn view(&self) -> PathView<'_> {
+        self.0.view(Direction::Into)
+    }
+
+    /// Overrides the trait called at each hop of the path.
+    pub fn set_trait(&mut self, conv: &ConvTrait) -> SynResult<()> {
+        self.0.set_trait(conv)
+    }
+
+    /// The containers the conversion gets lifted to, if any.
+    pub(super) fn lift(&self) -> Option<&Lift> {
+        self.0.lift.as_ref()
+    }
+
+    /// The conversion of the path lifted to the containers of its `lift(..)` flag, if any.
+    pub fn lifted(&self) -> Option<LiftedPath<'_>> {
+        let lift = self.0.lift.as_ref()?;
+        Some(LiftedPath::new(
+            lift,
+            &self.0.last_type,
+            Direction::Into,
+            false,
+        ))
     }
 }
 
@@ -21,32 +136,32 @@ impl ToTokens for TokenizablePath<'_, &TransitionInto> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let name = self.ident;
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
-        let first = &self.path.0.first_type;
         let last = &self.path.0.last_type;
 
-        let stmts = std::iter::once(first)
-            .chain(&self.path.0.intermediate_types)
-            .map(|ty| quote! {let val: #ty = core::convert::From::from(val);});
+        let bound = self.path.0.conv.bound(quote!(#name #ty_generics));
+        let method = self.path.0.conv.method();
+        let params = self.path.0.conv.params(quote!(#name #ty_generics));
+        let stmts = self.path.stmts(quote!(#name #ty_generics));
 
-        let types_check = distinct_types_check(
-            first,
-            last,
-            name,
-            &impl_generics,
-            &ty_generics,
-            where_clause,
-        );
+        let types_check = self
+            .path
+            .0
+            .distinct_types()
+            .map(|_| distinct_types_eval(name, &ty_generics));
 
         let expanded = quote! {
-            impl #impl_generics core::convert::From<#name #ty_generics> for #last #where_clause {
-                fn from(val: #name #ty_generics) -> #last {
+            impl #impl_generics #bound for #last #where_clause {
+                fn #method(#params) -> #last {
                     #types_check
                     #(#stmts)*
-                    core::convert::From::from(val)
                 }
             }
         };
 
         tokens.extend(expanded);
+
+        if let Some(lifted) = self.path.lifted() {
+            TokenizablePath::new(self.vis, name, self.generics, lifted).to_tokens(tokens);
+        }
     }
 }
@@ -1,14 +1,30 @@
+mod collect;
+mod default;
 mod from;
 mod into;
+mod serde;
 
+pub use collect::TransitionCollect;
+pub use default::TransitionDefault;
 pub use from::TransitionFrom;
 pub use into::TransitionInto;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+pub use serde::{SerdeFrom, SerdeInto};
 use syn::{
     parse::{Parse, ParseStream},
-    Result as SynResult, Type,
+    spanned::Spanned,
+    Error as SynError, Result as SynResult, Token, Type,
 };
 
-use crate::transitive::AtLeastTwoTypes;
+use crate::transitive::{
+    analysis::PathView,
+    conv_trait::ConvTrait,
+    distinct_types,
+    item::{Hop, HopList, HopPosition, PathItem, PathTypes, Step},
+    lift::{Direction, Lift},
+    parse_all, Errors,
+};
 
 struct TypeList {
     /// First type in the transitive conversion. ie. `A` in
@@ -20,26 +36,280 @@ struct TypeList {
     /// Last type in the transitive conversion. ie. `E` in
     /// `#[transitive(from(A, B, C, D, E))]`
     last_type: Type,
+    /// The conversion of each type in the list, in the same order.
+    hops: Vec<Hop>,
+    /// The function steps preceding each type in the list, in the same order.
+    steps: Vec<Vec<Step>>,
+    /// The function steps following the last type in the list.
+    trailing_steps: Vec<Step>,
+    lift: Option<Lift>,
+    /// Whether the first conversion of a `*_via` path is done from a reference.
+    by_ref: Option<Token![ref]>,
+    /// Whether the first and last types get checked for being distinct.
+    distinct_check: bool,
+    /// The trait called at each hop of the path.
+    conv: ConvTrait,
 }
 
 impl Parse for TypeList {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        let AtLeastTwoTypes {
+        Self::parse_with_tail(input, None, false)
+    }
+}
+
+impl TypeList {
+    /// Parses the list, appending the given type after the last one in the input. A `single` list
+    /// may consist of one type only, converted straight to the derived type.
+    fn parse_with_tail(
+        input: ParseStream,
+        tail: Option<(Hop, Type)>,
+        single: bool,
+    ) -> SynResult<Self> {
+        let error_span = input.span();
+
+        let mut types = PathTypes::default();
+        let mut lift = None;
+        let mut by_ref = None;
+        let mut distinct_check = true;
+
+        // Carry on past the faulty items so that all of their errors get reported at once.
+        let mut errors = Errors::default();
+
+        for item in parse_all::<PathItem>(input)? {
+            let Some(item) = errors.ok(item) else {
+                continue;
+            };
+
+            match item {
+                PathItem::Type(_, ty) if lift.is_some() => {
+                    let msg = "types not allowed after 'lift'";
+                    errors.push(SynError::new_spanned(ty, msg));
+                }
+                PathItem::Type(_, ty) if by_ref.is_some() => {
+                    let msg = "types not allowed after 'ref'";
+                    errors.push(SynError::new_spanned(ty, msg));
+                }
+                PathItem::Type(hop, ty) => types.push_type(hop, ty),
+                PathItem::Step(step) if lift.is_some() => {
+                    let msg = "steps not allowed after 'lift'";
+                    errors.push(SynError::new_spanned(step, msg));
+                }
+                PathItem::Step(step) if by_ref.is_some() => {
+                    let msg = "steps not allowed after 'ref'";
+                    errors.push(SynError::new_spanned(step, msg));
+                }
+                PathItem::Step(step) if step.try_token().is_some() => {
+                    let msg = "'try' steps not allowed in infallible paths";
+                    errors.push(SynError::new_spanned(step, msg));
+                }
+                PathItem::Step(step) => types.push_step(step),
+                PathItem::Error(err) => {
+                    let msg = "'error' not allowed in infallible paths";
+                    errors.push(SynError::new_spanned(err, msg));
+                }
+                PathItem::Fallback(fallback) => {
+                    let msg = "'fallback' not allowed in infallible paths";
+                    errors.push(SynError::new_spanned(fallback, msg));
+                }
+                PathItem::Lift(l) if lift.is_some() => {
+                    let msg = "'lift' not allowed multiple times";
+                    errors.push(SynError::new_spanned(l, msg));
+                }
+                PathItem::Lift(l) => lift = Some(l),
+                PathItem::NoDistinctCheck(flag) if !distinct_check => {
+                    let msg = "'no_distinct_check' not allowed multiple times";
+                    errors.push(SynError::new_spanned(flag, msg));
+                }
+                PathItem::NoDistinctCheck(_) => distinct_check = false,
+                PathItem::Ref(flag) if by_ref.is_some() => {
+                    let msg = "'ref' not allowed multiple times";
+                    errors.push(SynError::new_spanned(flag, msg));
+                }
+                PathItem::Ref(flag) => by_ref = Some(flag),
+            }
+        }
+
+        errors.finish()?;
+
+        if let Some((hop, ty)) = tail {
+            types.push_type(hop, ty);
+        }
+
+        let HopList {
             first_type,
-            second_type: mut last_type,
-            remaining,
-        } = AtLeastTwoTypes::parse(input)?;
+            intermediate_types,
+            last_type,
+            hops,
+            steps,
+            trailing_steps,
+        } = types.finish(error_span, single)?;
+
+        let output = Self {
+            first_type,
+            intermediate_types,
+            last_type,
+            hops,
+            steps,
+            trailing_steps,
+            lift,
+            by_ref,
+            distinct_check,
+            conv: ConvTrait::from(),
+        };
+
+        Ok(output)
+    }
+
+    /// Moves the marker of each type onto the hop leaving the type before it, as a marker applies
+    /// to the hop into the marked type while the hops of paths converting from their first type
+    /// are kept along with their source type. The first type has no hop into it, and the hop into
+    /// the derived type becomes `last`.
+    fn shift_hops(&mut self, last: Hop) -> SynResult<()> {
+        if !matches!(self.hops.first(), Some(Hop::Trait)) {
+            let msg = "hop markers not allowed on the first type";
+            return Err(SynError::new_spanned(&self.first_type, msg));
+        }
+
+        self.hops.remove(0);
+        self.hops.push(last);
+        Ok(())
+    }
 
-        let intermediate_types = remaining
-            .map(|ty| std::mem::replace(&mut last_type, ty))
-            .collect();
+    /// Returns an error if the `ref` flag is present, which only `*_via` paths support.
+    fn reject_ref(&self) -> SynResult<()> {
+        match &self.by_ref {
+            Some(flag) => Err(SynError::new_spanned(flag, "'ref' not allowed here")),
+            None => Ok(()),
+        }
+    }
+
+    /// The list going back from the last type to the first one, all hops going through the
+    /// conversion trait. Steps and hop markers cannot be reversed, so they are rejected.
+    fn reversed(&self) -> SynResult<Self> {
+        let steps = self.steps.iter().flatten().chain(&self.trailing_steps);
+        Step::reject(steps, "steps not allowed in paths converting both ways")?;
+
+        // A single type list holds the type as both the first and the last one.
+        let types = std::iter::once(&self.first_type)
+            .chain(&self.intermediate_types)
+            .chain(std::iter::once(&self.last_type))
+            .take(self.hops.len())
+            .collect::<Vec<_>>();
+
+        let mut reversed = PathTypes::default();
+
+        for (ty, hop) in types.into_iter().zip(&self.hops).rev() {
+            if !matches!(hop, Hop::Trait) {
+                let msg = "hop markers not allowed in paths converting both ways";
+                return Err(SynError::new_spanned(ty, msg));
+            }
+
+            reversed.push_type(Hop::Trait, ty.clone());
+        }
+
+        let HopList {
+            first_type,
+            intermediate_types,
+            last_type,
+            hops,
+            steps,
+            trailing_steps,
+        } = reversed.finish(Span::call_site(), true)?;
 
         let output = Self {
             first_type,
             intermediate_types,
             last_type,
+            hops,
+            steps,
+            trailing_steps,
+            lift: None,
+            by_ref: None,
+            distinct_check: false,
+            conv: self.conv.clone(),
         };
 
         Ok(output)
     }
+
+    /// The first and last types of the list, if they need to be checked for being distinct.
+    fn distinct_types(&self) -> Option<(&Type, &Type)> {
+        distinct_types(&self.first_type, &self.last_type, self.distinct_check)
+    }
+
+    /// A view of the types and hops of the list, in the given direction.
+    fn view(&self, direction: Direction) -> PathView<'_> {
+        PathView {
+            direction,
+            first_type: &self.first_type,
+            intermediate_types: &self.intermediate_types,
+            last_type: &self.last_type,
+            hops: &self.hops,
+            steps: &self.steps,
+            trailing_steps: &self.trailing_steps,
+            conv: Some(&self.conv),
+            generates: true,
+        }
+    }
+
+    /// Overrides the trait called at each hop of the path.
+    fn set_trait(&mut self, conv: &ConvTrait) -> SynResult<()> {
+        conv.reject_error_name()?;
+        if let Some(lift) = &self.lift {
+            let msg = "'lift' not allowed with a custom trait";
+            return Err(SynError::new_spanned(lift, msg));
+        }
+
+        self.conv = conv.clone();
+        Ok(())
+    }
+
+    /// Expression converting `val` to the target type through the given hop, inferring the source
+    /// type when unknown because of a preceding step.
+    fn call(
+        &self,
+        hop: &Hop,
+        source: Option<impl ToTokens>,
+        target: impl ToTokens,
+        span: Span,
+    ) -> TokenStream {
+        match hop {
+            Hop::Trait => self.conv.call(source, target, span),
+            Hop::Plain => {
+                let source = source.map_or_else(|| quote!(_), |s| s.to_token_stream());
+                quote_spanned!(span=> <#target as core::convert::From<#source>>::from(val))
+            }
+            Hop::Coerce => Hop::coerce(target),
+            Hop::Wrap(wrap) => wrap.clone(),
+        }
+    }
+
+    /// The position of the hop at the given zero based index, belonging to the given type.
+    fn position(&self, index: usize, ty: &Type) -> HopPosition {
+        HopPosition {
+            index: index + 1,
+            count: self.hops.len(),
+            // Only the location is taken from the type, so that the generated code is still seen
+            // as coming from the macro.
+            span: Span::call_site().located_at(ty.span()),
+        }
+    }
+
+    /// Statement checking the impl called at the given hop, unless its source type is unknown
+    /// because of a preceding step.
+    fn hop_check(
+        &self,
+        hop: &Hop,
+        source: Option<impl ToTokens>,
+        target: impl ToTokens,
+        position: HopPosition,
+    ) -> TokenStream {
+        let conv = match (hop, &source) {
+            (Hop::Trait, Some(_)) => self.conv.clone(),
+            (Hop::Plain, Some(_)) => ConvTrait::from(),
+            _ => return TokenStream::new(),
+        };
+
+        position.check(&conv, source, target)
+    }
 }
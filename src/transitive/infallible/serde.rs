@@ -0,0 +1,109 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Result as SynResult,
+};
+
+use super::{TransitionFrom, TransitionInto};
+use crate::transitive::{analysis::PathView, de_generics, lift::Lift, TokenizablePath};
+
+/// Path corresponding to a [`#[transitive(serde_into(..))`] path.
+///
+/// Behaves just like [`TransitionInto`], except that the last type in the path gets serialized
+/// instead of being returned.
+pub struct SerdeInto(TransitionInto);
+
+impl Parse for SerdeInto {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let path = TransitionInto::parse(input)?;
+        Lift::reject(path.lift())?;
+        Ok(Self(path))
+    }
+}
+
+impl SerdeInto {
+    /// A view of the types and hops of the path, which generates no conversion trait impl.
+    pub fn view(&self) -> PathView<'_> {
+        PathView {
+            generates: false,
+            ..self.0.view()
+        }
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &SerdeInto> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let last = self.path.0.last_type();
+        let stmts = self.path.0.stmts(quote!(#name #ty_generics));
+
+        let expanded = quote! {
+            impl #impl_generics ::serde::Serialize for #name #ty_generics #where_clause {
+                fn serialize<__S>(&self, serializer: __S) -> core::result::Result<__S::Ok, __S::Error>
+                where
+                    __S: ::serde::Serializer,
+                {
+                    let val: #last = {
+                        let val = core::clone::Clone::clone(self);
+                        #(#stmts)*
+                    };
+
+                    ::serde::Serialize::serialize(&val, serializer)
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
+
+/// Path corresponding to a [`#[transitive(serde_from(..))`] path.
+///
+/// Behaves just like [`TransitionFrom`], except that the first type in the path gets
+/// deserialized instead of being an argument.
+pub struct SerdeFrom(TransitionFrom);
+
+impl Parse for SerdeFrom {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let path = TransitionFrom::parse(input)?;
+        Lift::reject(path.lift())?;
+        Ok(Self(path))
+    }
+}
+
+impl SerdeFrom {
+    /// A view of the types and hops of the path, which generates no conversion trait impl.
+    pub fn view(&self) -> PathView<'_> {
+        PathView {
+            generates: false,
+            ..self.0.view()
+        }
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &SerdeFrom> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.ident;
+        let generics = de_generics(self.generics);
+        let (impl_generics, _, _) = generics.split_for_impl();
+        let (_, ty_generics, where_clause) = self.generics.split_for_impl();
+        let first = self.path.0.first_type();
+        let stmts = self.path.0.stmts();
+
+        let expanded = quote! {
+            impl #impl_generics ::serde::Deserialize<'de> for #name #ty_generics #where_clause {
+                fn deserialize<__D>(deserializer: __D) -> core::result::Result<Self, __D::Error>
+                where
+                    __D: ::serde::Deserializer<'de>,
+                {
+                    let val: #first = ::serde::Deserialize::deserialize(deserializer)?;
+                    core::result::Result::Ok({ #(#stmts)* })
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
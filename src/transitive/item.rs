@@ -0,0 +1,369 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{discouraged::Speculative, Parse, ParseStream},
+    token::Paren,
+    Error as SynError, Expr, ExprPath, Ident, Result as SynResult, Token, Type,
+};
+
+use crate::transitive::{conv_trait::ConvTrait, lift::Lift};
+
+/// An item in the parameters list of a path attribute.
+pub enum PathItem {
+    Type(Hop, Type),
+    Step(Step),
+    Error(Type),
+    Lift(Lift),
+    /// Opts the path out of the check that its first and last types are distinct.
+    NoDistinctCheck(Ident),
+    /// Makes the first conversion of a `*_via` path take the derived type by reference.
+    Ref(Token![ref]),
+    /// The function a `from_or_else` path passes the error to, ie. `fallback = my::fallback_fn`.
+    Fallback(ExprPath),
+}
+
+impl PathItem {
+    const ERROR: &'static str = "error";
+    const LIFT: &'static str = "lift";
+    const VIA: &'static str = "via";
+    const NO_DISTINCT_CHECK: &'static str = "no_distinct_check";
+    const FALLBACK: &'static str = "fallback";
+}
+
+impl Parse for PathItem {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        // A fallible step is marked with `try`, which is a keyword and cannot start a type.
+        let try_token = input.parse::<Option<Token![try]>>()?;
+
+        if input.peek(Token![fn]) {
+            let fn_token = input.parse()?;
+            return input
+                .parse()
+                .map(|path| Self::Step(Step::Fn(try_token, fn_token, path)));
+        }
+
+        if let Some(try_token) = try_token {
+            let ident = input
+                .parse::<Ident>()
+                .ok()
+                .filter(|ident| ident == Self::VIA);
+            let Some(ident) = ident else {
+                let msg = "expected a 'fn' or 'via' step after 'try'";
+                return Err(SynError::new_spanned(try_token, msg));
+            };
+
+            input.parse::<Token![=]>()?;
+            return input
+                .parse()
+                .map(|expr| Self::Step(Step::Via(Some(try_token), ident, expr)));
+        }
+
+        if input.peek(Token![ref]) {
+            return input.parse().map(Self::Ref);
+        }
+
+        // A type could look the same, so look for the exact ident followed by parentheses.
+        if input.peek(Ident) && input.peek2(Paren) {
+            let fork = input.fork();
+            if fork.parse::<Ident>()? == Self::LIFT {
+                return input.parse().map(Self::Lift);
+            }
+        }
+
+        let fork = input.fork();
+        // A flag is a lone ident, so it must be followed by a comma or nothing at all
+        let is_flag = fork.parse::<Ident>().is_ok_and(|ident| {
+            ident == Self::NO_DISTINCT_CHECK && (fork.is_empty() || fork.peek(Token![,]))
+        });
+
+        if is_flag {
+            return input.parse().map(Self::NoDistinctCheck);
+        }
+
+        let fork = input.fork();
+        // Parse the ident name and the equal sign after it
+        let res = fork
+            .parse::<Ident>()
+            .and_then(|ident| fork.parse::<Token![=]>().map(|_| ident));
+
+        match res {
+            // We got an `error = MyType` argument
+            Ok(path) if path == Self::ERROR => {
+                input.advance_to(&fork);
+                input.parse().map(Self::Error)
+            }
+            // We got a `fallback = my::fallback_fn` argument
+            Ok(ident) if ident == Self::FALLBACK => {
+                input.advance_to(&fork);
+                input.parse().map(Self::Fallback)
+            }
+            // We got a `via = |val| ..` step
+            Ok(ident) if ident == Self::VIA => {
+                input.advance_to(&fork);
+                input
+                    .parse()
+                    .map(|expr| Self::Step(Step::Via(None, ident, expr)))
+            }
+            // Try to parse anything else as a possibly marked type in the path list
+            _ => {
+                let hop = input.parse()?;
+                input.parse().map(|ty| Self::Type(hop, ty))
+            }
+        }
+    }
+}
+
+/// Accumulates the types of a path along with the hops and steps between them.
+#[derive(Default)]
+pub struct PathTypes {
+    types: Vec<Type>,
+    /// The conversion of each type in the list, in the same order.
+    hops: Vec<Hop>,
+    /// The function steps preceding each type in the list, in the same order.
+    steps: Vec<Vec<Step>>,
+    /// Function steps not yet followed by a type.
+    pending: Vec<Step>,
+}
+
+impl PathTypes {
+    pub fn push_type(&mut self, hop: Hop, ty: Type) {
+        self.types.push(ty);
+        self.hops.push(hop);
+        self.steps.push(std::mem::take(&mut self.pending));
+    }
+
+    pub fn push_step(&mut self, step: Step) {
+        self.pending.push(step);
+    }
+
+    /// Splits the accumulated types, requiring at least two of them unless `single` is set, in
+    /// which case a lone type is both the first and the last one.
+    ///
+    /// ```compile_fail
+    /// use transitive::Transitive;
+    ///
+    /// struct A;
+    /// #[derive(Transitive)]
+    /// #[transitive(from(A))] // fails to compile, list too short
+    /// struct B;
+    ///
+    /// impl From<A> for B {
+    ///     fn from(_: A) -> B {
+    ///         Self
+    ///     }
+    /// }
+    /// ```
+    pub fn finish(self, error_span: Span, single: bool) -> SynResult<HopList> {
+        let mut types = self.types.into_iter();
+
+        let (first_type, mut last_type) = match (types.next(), types.next()) {
+            (Some(first_type), Some(last_type)) => (first_type, last_type),
+            (Some(first_type), None) if single => (first_type.clone(), first_type),
+            _ if single => return Err(SynError::new(error_span, "at least one type required")),
+            _ => return Err(SynError::new(error_span, "at least two types required")),
+        };
+
+        let mut intermediate_types = Vec::with_capacity(types.len());
+
+        for ty in types {
+            intermediate_types.push(last_type);
+            last_type = ty;
+        }
+
+        let output = HopList {
+            first_type,
+            intermediate_types,
+            last_type,
+            hops: self.hops,
+            steps: self.steps,
+            trailing_steps: self.pending,
+        };
+
+        Ok(output)
+    }
+}
+
+/// The types of a path split by their position, along with the hops and steps between them.
+pub struct HopList {
+    pub first_type: Type,
+    pub intermediate_types: Vec<Type>,
+    pub last_type: Type,
+    pub hops: Vec<Hop>,
+    pub steps: Vec<Vec<Step>>,
+    pub trailing_steps: Vec<Step>,
+}
+
+/// A function applied to the value in between two types of a path, fallible if marked with `try`.
+pub enum Step {
+    /// `fn Duration::from_secs` or `try fn parse_port`
+    Fn(Option<Token![try]>, Token![fn], ExprPath),
+    /// `via = |val| Duration::from_secs(val)` or `try via = |val| val.parse()`
+    Via(Option<Token![try]>, Ident, Expr),
+}
+
+impl Step {
+    /// The `try` marker of a step returning a [`Result`], if present.
+    pub fn try_token(&self) -> Option<&Token![try]> {
+        match self {
+            Step::Fn(try_token, ..) | Step::Via(try_token, ..) => try_token.as_ref(),
+        }
+    }
+
+    /// Expression applying the step to `val`.
+    pub fn call(&self) -> TokenStream {
+        match self {
+            Step::Fn(_, _, path) => quote!(#path(val)),
+            Step::Via(_, _, expr) => quote!((#expr)(val)),
+        }
+    }
+
+    /// Statement applying the step to `val`, propagating its error if the step is fallible.
+    pub fn stmt(&self) -> TokenStream {
+        let call = self.call();
+
+        match self.try_token() {
+            Some(_) => quote! {let val = #call?;},
+            None => quote! {let val = #call;},
+        }
+    }
+
+    /// Returns an error if any steps are present in a position or path that does not support
+    /// them.
+    pub fn reject<'a>(steps: impl IntoIterator<Item = &'a Step>, msg: &str) -> SynResult<()> {
+        match steps.into_iter().next() {
+            Some(step) => Err(SynError::new_spanned(step, msg)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ToTokens for Step {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Step::Fn(try_token, fn_token, path) => {
+                try_token.to_tokens(tokens);
+                fn_token.to_tokens(tokens);
+                path.to_tokens(tokens);
+            }
+            Step::Via(try_token, ident, expr) => {
+                try_token.to_tokens(tokens);
+                ident.to_tokens(tokens);
+                expr.to_tokens(tokens);
+            }
+        }
+    }
+}
+
+/// The conversion done by a single hop of a path.
+pub enum Hop {
+    /// The conversion trait of the path, possibly a custom one.
+    Trait,
+    /// Plain [`From`] or [`TryFrom`], ignoring the custom trait and context of the path, ie.
+    /// `plain B`.
+    Plain,
+    /// An unsize coercion through a typed binding, ie. `coerce Box<dyn Trait>`.
+    Coerce,
+    /// Wrapping the value in an enum variant, ie. `Self::Variant(val)`.
+    Wrap(TokenStream),
+}
+
+impl Hop {
+    const PLAIN: &'static str = "plain";
+    const COERCE: &'static str = "coerce";
+
+    /// Expression coercing `val` to the target type.
+    pub fn coerce(target: impl ToTokens) -> TokenStream {
+        quote!({
+            let val: #target = val;
+            val
+        })
+    }
+}
+
+/// The position of a hop in its path, used to point at the hop in diagnostics.
+#[derive(Clone, Copy)]
+pub struct HopPosition {
+    /// The one based index of the hop.
+    #[cfg_attr(not(feature = "diagnostics"), allow(dead_code))]
+    pub index: usize,
+    /// The number of hops in the path.
+    #[cfg_attr(not(feature = "diagnostics"), allow(dead_code))]
+    pub count: usize,
+    /// The span of the type the hop belongs to.
+    pub span: Span,
+}
+
+impl HopPosition {
+    /// Statement checking that the hop from `source` to `target` goes through an implemented
+    /// `conv` trait. Thanks to `#[diagnostic::on_unimplemented]`, a missing impl gets reported as
+    /// the hop of the path that it belongs to.
+    ///
+    /// The check declares items of its own, which cannot refer to the generics of the derived
+    /// type, so it is skipped for traits naming them. Their generic parameters get unusual names
+    /// so that they do not capture the ones of the trait path and context type.
+    #[cfg(feature = "diagnostics")]
+    pub fn check(
+        &self,
+        conv: &ConvTrait,
+        source: impl ToTokens,
+        target: impl ToTokens,
+    ) -> TokenStream {
+        if conv.is_generic() {
+            return TokenStream::new();
+        }
+
+        let bound = conv.bound(quote!(__TransitiveS));
+        let message = format!(
+            "transitive hop {} of {}: `{{Self}}` must implement `{}<{{__TransitiveS}}>`",
+            self.index,
+            self.count,
+            conv.name()
+        );
+
+        quote::quote_spanned! {self.span=>
+            {
+                #[diagnostic::on_unimplemented(message = #message)]
+                trait TransitiveHop<__TransitiveS> {}
+
+                impl<__TransitiveS, __TransitiveT: #bound> TransitiveHop<__TransitiveS>
+                    for __TransitiveT
+                {
+                }
+
+                fn hop<__TransitiveS, __TransitiveT: TransitiveHop<__TransitiveS>>() {}
+
+                hop::<#source, #target>();
+            }
+        }
+    }
+
+    /// Diagnostic hop checks require the `diagnostics` feature.
+    #[cfg(not(feature = "diagnostics"))]
+    pub fn check(
+        &self,
+        _conv: &ConvTrait,
+        _source: impl ToTokens,
+        _target: impl ToTokens,
+    ) -> TokenStream {
+        TokenStream::new()
+    }
+}
+
+impl Parse for Hop {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let fork = input.fork();
+        // A marker is an ident followed by the type, so make sure it is not a type itself.
+        let marker = fork.parse::<Ident>().ok().filter(|_| {
+            !(fork.is_empty()
+                || fork.peek(Token![,])
+                || fork.peek(Token![::])
+                || fork.peek(Token![<]))
+        });
+
+        match marker {
+            Some(ident) if ident == Self::PLAIN => input.parse::<Ident>().map(|_| Self::Plain),
+            Some(ident) if ident == Self::COERCE => input.parse::<Ident>().map(|_| Self::Coerce),
+            _ => Ok(Self::Trait),
+        }
+    }
+}
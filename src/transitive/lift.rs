@@ -0,0 +1,279 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Error as SynError, Ident, Result as SynResult, Token, Type,
+};
+
+use crate::transitive::{method_ident, TokenizablePath};
+
+/// The `lift(..)` flag of a path, listing the containers the conversion gets lifted to.
+pub struct Lift {
+    ident: Ident,
+    containers: Vec<Container>,
+}
+
+impl Lift {
+    /// Returns an error if the flag is present in a path that does not support it.
+    pub fn reject(lift: Option<&Self>) -> SynResult<()> {
+        match lift {
+            Some(lift) => Err(SynError::new_spanned(
+                &lift.ident,
+                "'lift' not allowed here",
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Parse for Lift {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let ident = input.parse()?;
+        let content;
+        syn::parenthesized!(content in input);
+
+        let containers = Punctuated::<Container, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .collect();
+
+        Ok(Self { ident, containers })
+    }
+}
+
+impl ToTokens for Lift {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.ident.to_tokens(tokens);
+    }
+}
+
+/// A container type that a conversion can be lifted to.
+enum Container {
+    Option,
+    Vec,
+    Result,
+    Box,
+}
+
+impl Container {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Option => "option",
+            Self::Vec => "vec",
+            Self::Result => "result",
+            Self::Box => "box",
+        }
+    }
+}
+
+impl Parse for Container {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let ident = input.parse::<Ident>()?;
+
+        match ident.to_string().as_str() {
+            "Option" => Ok(Self::Option),
+            "Vec" => Ok(Self::Vec),
+            "Result" => Ok(Self::Result),
+            "Box" => Ok(Self::Box),
+            _ => {
+                let msg = "unknown container, expected one of 'Option', 'Vec', 'Result' or 'Box'";
+                Err(SynError::new(ident.span(), msg))
+            }
+        }
+    }
+}
+
+/// The direction of a conversion relative to the derived type.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Direction {
+    /// The derived type is the target of the conversion.
+    From,
+    /// The derived type is the source of the conversion.
+    Into,
+}
+
+/// A [`Lift`] applied to the conversion between the derived type and another type.
+pub struct LiftedPath<'a> {
+    lift: &'a Lift,
+    other: &'a Type,
+    direction: Direction,
+    fallible: bool,
+}
+
+impl<'a> LiftedPath<'a> {
+    pub fn new(lift: &'a Lift, other: &'a Type, direction: Direction, fallible: bool) -> Self {
+        Self {
+            lift,
+            other,
+            direction,
+            fallible,
+        }
+    }
+
+    /// Prefix of the generated method names, ie. `try_from` for a fallible conversion to the
+    /// derived type.
+    fn prefix(&self) -> String {
+        let prefix = match self.direction {
+            Direction::From => "from",
+            Direction::Into => "into",
+        };
+
+        match self.fallible {
+            false => prefix.to_owned(),
+            true => format!("try_{prefix}"),
+        }
+    }
+
+    /// Whether the container gets a trait impl instead of an inherent method.
+    fn is_impl(&self, container: &Container) -> bool {
+        matches!(container, Container::Box) && self.direction == Direction::From
+    }
+
+    /// The names of the generated inherent methods.
+    pub fn methods(&self) -> SynResult<Vec<Ident>> {
+        let prefix = self.prefix();
+
+        self.lift
+            .containers
+            .iter()
+            .filter(|container| !self.is_impl(container))
+            .map(|container| method_ident(&format!("{prefix}_{}", container.name()), self.other))
+            .collect()
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, LiftedPath<'_>> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let vis = self.vis;
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let other = self.path.other;
+        let derived = quote!(#name #ty_generics);
+
+        let prefix = self.path.prefix();
+
+        let (source, target) = match self.path.direction {
+            Direction::From => (quote!(#other), derived.clone()),
+            Direction::Into => (derived.clone(), quote!(#other)),
+        };
+
+        // The conversion function of the generated path impl and its error type, if fallible.
+        let (conv, error) = match self.path.fallible {
+            false => (
+                quote!(<#target as core::convert::From<#source>>::from),
+                None,
+            ),
+            true => (
+                quote!(<#target as core::convert::TryFrom<#source>>::try_from),
+                Some(quote!(<#target as core::convert::TryFrom<#source>>::Error)),
+            ),
+        };
+
+        let ret = |ty: TokenStream| match &error {
+            Some(error) => quote!(core::result::Result<#ty, #error>),
+            None => ty,
+        };
+
+        let mut methods = TokenStream::new();
+
+        for container in &self.path.lift.containers {
+            let method = match method_ident(&format!("{prefix}_{}", container.name()), other) {
+                Ok(method) => method,
+                Err(e) => return tokens.extend(e.to_compile_error()),
+            };
+
+            let method = match (container, self.path.fallible) {
+                (Container::Option, false) => quote! {
+                    #vis fn #method(val: core::option::Option<#source>) -> core::option::Option<#target> {
+                        val.map(#conv)
+                    }
+                },
+                (Container::Option, true) => {
+                    let ret = ret(quote!(core::option::Option<#target>));
+                    quote! {
+                        #vis fn #method(val: core::option::Option<#source>) -> #ret {
+                            val.map(#conv).transpose()
+                        }
+                    }
+                }
+                (Container::Vec, _) => {
+                    let ret = ret(quote!(std::vec::Vec<#target>));
+                    quote! {
+                        #vis fn #method(val: std::vec::Vec<#source>) -> #ret {
+                            val.into_iter().map(#conv).collect()
+                        }
+                    }
+                }
+                (Container::Result, false) => quote! {
+                    #vis fn #method<__E>(val: core::result::Result<#source, __E>) -> core::result::Result<#target, __E> {
+                        val.map(#conv)
+                    }
+                },
+                (Container::Result, true) => {
+                    let ret = ret(quote!(core::result::Result<#target, __E>));
+                    quote! {
+                        #vis fn #method<__E>(val: core::result::Result<#source, __E>) -> #ret {
+                            match val {
+                                core::result::Result::Ok(val) => {
+                                    #conv(val).map(core::result::Result::Ok)
+                                }
+                                core::result::Result::Err(e) => {
+                                    core::result::Result::Ok(core::result::Result::Err(e))
+                                }
+                            }
+                        }
+                    }
+                }
+                // `Box` is a fundamental type, so `Box<A>` is local if `A` is and the conversion
+                // can be implemented as a trait.
+                (Container::Box, false) if self.path.is_impl(container) => {
+                    tokens.extend(quote! {
+                        impl #impl_generics core::convert::From<std::boxed::Box<#source>> for std::boxed::Box<#target> #where_clause {
+                            fn from(val: std::boxed::Box<#source>) -> Self {
+                                std::boxed::Box::new(#conv(*val))
+                            }
+                        }
+                    });
+                    continue;
+                }
+                (Container::Box, true) if self.path.is_impl(container) => {
+                    tokens.extend(quote! {
+                        impl #impl_generics core::convert::TryFrom<std::boxed::Box<#source>> for std::boxed::Box<#target> #where_clause {
+                            type Error = #error;
+
+                            fn try_from(val: std::boxed::Box<#source>) -> core::result::Result<Self, Self::Error> {
+                                #conv(*val).map(std::boxed::Box::new)
+                            }
+                        }
+                    });
+                    continue;
+                }
+                (Container::Box, false) => quote! {
+                    #[allow(clippy::boxed_local)]
+                    #vis fn #method(val: std::boxed::Box<#source>) -> std::boxed::Box<#target> {
+                        std::boxed::Box::new(#conv(*val))
+                    }
+                },
+                (Container::Box, true) => {
+                    let ret = ret(quote!(std::boxed::Box<#target>));
+                    quote! {
+                        #[allow(clippy::boxed_local)]
+                        #vis fn #method(val: std::boxed::Box<#source>) -> #ret {
+                            #conv(*val).map(std::boxed::Box::new)
+                        }
+                    }
+                }
+            };
+
+            methods.extend(method);
+        }
+
+        if !methods.is_empty() {
+            tokens.extend(quote! {
+                impl #impl_generics #name #ty_generics #where_clause {
+                    #methods
+                }
+            });
+        }
+    }
+}
@@ -1,19 +1,42 @@
+mod analysis;
+mod cast_ref;
+mod conv_trait;
+mod deref;
 mod fallible;
 mod infallible;
-
-use fallible::{TryTransitionFrom, TryTransitionInto};
-use infallible::{TransitionFrom, TransitionInto};
-use proc_macro2::TokenStream;
+mod item;
+mod lift;
+mod via;
+mod wrap;
+
+use std::collections::{hash_map::Entry, HashMap};
+
+use analysis::PathView;
+use cast_ref::CastRef;
+use conv_trait::{ConvTrait, TraitOption};
+use deref::DerefTo;
+use fallible::{
+    AsyncTryTransitionFrom, CheckedFrom, CheckedInto, TransitionFromOrDefault,
+    TransitionFromOrElse, TransitionFromStr, TrySerdeFrom, TrySerdeInto, TryTransitionDefault,
+    TryTransitionFrom, TryTransitionInto,
+};
+use infallible::{
+    SerdeFrom, SerdeInto, TransitionCollect, TransitionDefault, TransitionFrom, TransitionInto,
+};
+pub use item::Hop;
+use lift::LiftedPath;
+use proc_macro2::{TokenStream, TokenTree};
 use quote::{quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
-    punctuated::Punctuated,
-    DeriveInput, Error as SynError, Generics, Ident, ImplGenerics, MetaList, Result as SynResult,
-    Token, Type, TypeGenerics, WhereClause,
+    parse_quote, Attribute, Data, DeriveInput, Error as SynError, Generics, Ident, MetaList,
+    Result as SynResult, Token, Type, TypeGenerics, TypePath, Visibility,
 };
+use via::{DebugVia, DisplayVia, EqVia, OpsVia, OrdVia, ViaOption};
 
 /// The input to the [`crate::Transitive`] derive macro.
 pub struct TransitiveInput {
+    vis: Visibility,
     ident: Ident,
     generics: Generics,
     paths: Vec<TransitionPath>,
@@ -27,23 +50,79 @@ impl Parse for TransitiveInput {
     fn parse(input: ParseStream) -> SynResult<Self> {
         let DeriveInput {
             attrs,
+            vis,
             ident,
             generics,
+            data,
             ..
         } = DeriveInput::parse(input)?;
 
-        let fold_fn = |mut vec: Vec<TransitionPath>, res| {
-            vec.extend(res?);
-            Ok(vec)
-        };
+        let mut errors = Errors::default();
+        let mut paths = Vec::new();
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident(Self::ATTR_NAME)) {
+            let Some(items) = errors.ok(attr.parse_args_with(parse_all::<AttrItem>)) else {
+                continue;
+            };
+
+            let mut attr_paths = Vec::new();
+            let mut options = Vec::new();
+            let mut via_options = Vec::new();
+
+            for item in items.into_iter().filter_map(|item| errors.ok(item)) {
+                match item {
+                    AttrItem::Path(path) => attr_paths.push(*path),
+                    AttrItem::Option(option) => options.push(option),
+                    AttrItem::Via(option) => via_options.push(option),
+                }
+            }
+
+            // Options apply to all the paths of the attribute they are in.
+            if let Some(conv) = errors
+                .ok(ConvTrait::from_options(options, &generics))
+                .flatten()
+            {
+                for path in &mut attr_paths {
+                    errors.ok(path.set_trait(&conv));
+                }
+            }
+
+            for option in &via_options {
+                if attr_paths.is_empty() {
+                    let msg = format!("'{}' requires a path in the same attribute", option.key());
+                    errors.push(SynError::new_spanned(option.key(), msg));
+                }
 
-        let paths = attrs
-            .into_iter()
-            .filter(|a| a.path().is_ident(Self::ATTR_NAME))
-            .map(|a| a.parse_args_with(Punctuated::<_, Token![,]>::parse_terminated))
-            .try_fold::<_, _, SynResult<_>>(Vec::new(), fold_fn)?;
+                for path in &mut attr_paths {
+                    errors.ok(path.set_via_option(option));
+                }
+            }
+
+            paths.extend(attr_paths);
+        }
+
+        match &data {
+            Data::Enum(data) => {
+                for variant in &data.variants {
+                    paths.extend(wrap::variant_paths(variant, &mut errors));
+                }
+            }
+            Data::Struct(data) => paths.extend(wrap::field_paths(&data.fields, &mut errors)),
+            Data::Union(data) => wrap::union_fields(&data.fields, &mut errors),
+        }
+
+        for path in &paths {
+            errors.ok(path.check_input(&attrs, &data));
+        }
+
+        check_methods(&paths, &mut errors);
+
+        let views = paths.iter().map(TransitionPath::view).collect::<Vec<_>>();
+        analysis::check_paths(&views, &ident, &mut errors);
+        errors.finish()?;
 
         let output = Self {
+            vis,
             ident,
             generics,
             paths,
@@ -55,8 +134,35 @@ impl Parse for TransitiveInput {
 
 impl ToTokens for TransitiveInput {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let pairs = self
+            .paths
+            .iter()
+            .filter_map(TransitionPath::distinct_types)
+            .collect::<Vec<_>>();
+
+        distinct_types_check(&pairs, &self.ident, &self.generics).to_tokens(tokens);
+
         for path in &self.paths {
-            TokenizablePath::new(&self.ident, &self.generics, path).to_tokens(tokens);
+            TokenizablePath::new(&self.vis, &self.ident, &self.generics, path).to_tokens(tokens);
+        }
+    }
+}
+
+/// An item in a `#[transitive(..)]` attribute, either a path or an option applying to the paths.
+enum AttrItem {
+    Path(Box<TransitionPath>),
+    Option(TraitOption),
+    Via(ViaOption),
+}
+
+impl Parse for AttrItem {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        if TraitOption::peek(input) {
+            input.parse().map(Self::Option)
+        } else if ViaOption::peek(input) {
+            input.parse().map(Self::Via)
+        } else {
+            input.parse().map(Self::Path)
         }
     }
 }
@@ -67,6 +173,26 @@ enum TransitionPath {
     Into(TransitionInto),
     TryFrom(TryTransitionFrom),
     TryInto(TryTransitionInto),
+    DerefTo(DerefTo),
+    FromStr(TransitionFromStr),
+    DefaultVia(TransitionDefault),
+    TryDefaultVia(TryTransitionDefault),
+    EqVia(EqVia),
+    OrdVia(OrdVia),
+    DisplayVia(DisplayVia),
+    DebugVia(DebugVia),
+    SerdeInto(SerdeInto),
+    SerdeFrom(SerdeFrom),
+    TrySerdeInto(TrySerdeInto),
+    TrySerdeFrom(TrySerdeFrom),
+    OpsVia(OpsVia),
+    CollectFrom(TransitionCollect),
+    AsyncTryFrom(AsyncTryTransitionFrom),
+    CheckedFrom(CheckedFrom),
+    CheckedInto(CheckedInto),
+    FromOrDefault(TransitionFromOrDefault),
+    FromOrElse(TransitionFromOrElse),
+    CastRef(CastRef),
 }
 
 impl TransitionPath {
@@ -74,6 +200,160 @@ impl TransitionPath {
     const INTO: &'static str = "into";
     const TRY_FROM: &'static str = "try_from";
     const TRY_INTO: &'static str = "try_into";
+    const DEREF_TO: &'static str = "deref_to";
+    const FROM_STR: &'static str = "from_str";
+    const DEFAULT_VIA: &'static str = "default_via";
+    const TRY_DEFAULT_VIA: &'static str = "try_default_via";
+    const EQ_VIA: &'static str = "eq_via";
+    const ORD_VIA: &'static str = "ord_via";
+    const DISPLAY_VIA: &'static str = "display_via";
+    const DEBUG_VIA: &'static str = "debug_via";
+    const SERDE_INTO: &'static str = "serde_into";
+    const SERDE_FROM: &'static str = "serde_from";
+    const TRY_SERDE_INTO: &'static str = "try_serde_into";
+    const TRY_SERDE_FROM: &'static str = "try_serde_from";
+    const OPS_VIA: &'static str = "ops_via";
+    const COLLECT_FROM: &'static str = "collect_from";
+    const ASYNC_TRY_FROM: &'static str = "async_try_from";
+    const CHECKED_FROM: &'static str = "checked_from";
+    const CHECKED_INTO: &'static str = "checked_into";
+    const FROM_OR_DEFAULT: &'static str = "from_or_default";
+    const FROM_OR_ELSE: &'static str = "from_or_else";
+    const CAST_REF: &'static str = "cast_ref";
+
+    /// All the path names, used to suggest the closest one to an unknown parameter.
+    const NAMES: [&'static str; 24] = [
+        Self::FROM,
+        Self::INTO,
+        Self::TRY_FROM,
+        Self::TRY_INTO,
+        Self::DEREF_TO,
+        Self::FROM_STR,
+        Self::DEFAULT_VIA,
+        Self::TRY_DEFAULT_VIA,
+        Self::EQ_VIA,
+        Self::ORD_VIA,
+        Self::DISPLAY_VIA,
+        Self::DEBUG_VIA,
+        Self::SERDE_INTO,
+        Self::SERDE_FROM,
+        Self::TRY_SERDE_INTO,
+        Self::TRY_SERDE_FROM,
+        Self::OPS_VIA,
+        Self::COLLECT_FROM,
+        Self::ASYNC_TRY_FROM,
+        Self::CHECKED_FROM,
+        Self::CHECKED_INTO,
+        Self::FROM_OR_DEFAULT,
+        Self::FROM_OR_ELSE,
+        Self::CAST_REF,
+    ];
+}
+
+impl TransitionPath {
+    /// Overrides the conversion trait called at each hop of the path.
+    fn set_trait(&mut self, conv: &ConvTrait) -> SynResult<()> {
+        match self {
+            TransitionPath::From(from) => from.set_trait(conv),
+            TransitionPath::Into(into) => into.set_trait(conv),
+            TransitionPath::TryFrom(try_from) => try_from.set_trait(conv),
+            TransitionPath::TryInto(try_into) => try_into.set_trait(conv),
+            TransitionPath::AsyncTryFrom(async_try_from) => async_try_from.set_trait(conv),
+            _ => {
+                let msg = "custom traits not allowed for this path";
+                Err(SynError::new_spanned(conv.path(), msg))
+            }
+        }
+    }
+
+    /// Applies a `with(..)` or `ops(..)` option to the path.
+    fn set_via_option(&mut self, option: &ViaOption) -> SynResult<()> {
+        match self {
+            TransitionPath::EqVia(eq_via) => eq_via.set_option(option),
+            TransitionPath::OrdVia(ord_via) => ord_via.set_option(option),
+            TransitionPath::OpsVia(ops_via) => ops_via.set_option(option),
+            _ => {
+                let msg = format!("'{}' not allowed for this path", option.key());
+                Err(SynError::new_spanned(option.key(), msg))
+            }
+        }
+    }
+
+    /// A view of the types and hops of the path, for analyzing the conversions it relies on.
+    fn view(&self) -> PathView<'_> {
+        match self {
+            TransitionPath::From(from) => from.view(),
+            TransitionPath::Into(into) => into.view(),
+            TransitionPath::TryFrom(try_from) => try_from.view(),
+            TransitionPath::TryInto(try_into) => try_into.view(),
+            TransitionPath::DerefTo(deref_to) => deref_to.view(),
+            TransitionPath::FromStr(from_str) => from_str.view(),
+            TransitionPath::DefaultVia(default_via) => default_via.view(),
+            TransitionPath::TryDefaultVia(try_default_via) => try_default_via.view(),
+            TransitionPath::EqVia(eq_via) => eq_via.view(),
+            TransitionPath::OrdVia(ord_via) => ord_via.view(),
+            TransitionPath::DisplayVia(display_via) => display_via.view(),
+            TransitionPath::DebugVia(debug_via) => debug_via.view(),
+            TransitionPath::SerdeInto(serde_into) => serde_into.view(),
+            TransitionPath::SerdeFrom(serde_from) => serde_from.view(),
+            TransitionPath::TrySerdeInto(try_serde_into) => try_serde_into.view(),
+            TransitionPath::TrySerdeFrom(try_serde_from) => try_serde_from.view(),
+            TransitionPath::OpsVia(ops_via) => ops_via.view(),
+            TransitionPath::CollectFrom(collect_from) => collect_from.view(),
+            TransitionPath::AsyncTryFrom(async_try_from) => async_try_from.view(),
+            TransitionPath::CheckedFrom(checked_from) => checked_from.view(),
+            TransitionPath::CheckedInto(checked_into) => checked_into.view(),
+            TransitionPath::FromOrDefault(from_or_default) => from_or_default.view(),
+            TransitionPath::FromOrElse(from_or_else) => from_or_else.view(),
+            TransitionPath::CastRef(cast_ref) => cast_ref.view(),
+        }
+    }
+
+    /// The names of the inherent methods the path generates on the derived type.
+    fn methods(&self) -> SynResult<Vec<Ident>> {
+        let lifted = |lifted: Option<LiftedPath>| lifted.map_or(Ok(Vec::new()), |l| l.methods());
+
+        match self {
+            TransitionPath::From(from) => lifted(from.lifted()),
+            TransitionPath::Into(into) => lifted(into.lifted()),
+            TransitionPath::TryFrom(try_from) => lifted(try_from.lifted()),
+            TransitionPath::TryInto(try_into) => lifted(try_into.lifted()),
+            TransitionPath::DerefTo(deref_to) => Ok(deref_to.methods()),
+            TransitionPath::CheckedFrom(checked_from) => checked_from.method().map(|m| vec![m]),
+            TransitionPath::CheckedInto(checked_into) => checked_into.method().map(|m| vec![m]),
+            TransitionPath::CastRef(cast_ref) => Ok(cast_ref.methods()),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// The first and last types of the path, if they need to be checked for being distinct.
+    fn distinct_types(&self) -> Option<(&Type, &Type)> {
+        match self {
+            TransitionPath::From(from) => from.distinct_types(),
+            TransitionPath::Into(into) => into.distinct_types(),
+            TransitionPath::TryFrom(try_from) => try_from.distinct_types(),
+            TransitionPath::TryInto(try_into) => try_into.distinct_types(),
+            TransitionPath::CollectFrom(collect_from) => collect_from.distinct_types(),
+            TransitionPath::AsyncTryFrom(async_try_from) => async_try_from.distinct_types(),
+            TransitionPath::CheckedFrom(checked_from) => checked_from.distinct_types(),
+            TransitionPath::CheckedInto(checked_into) => checked_into.distinct_types(),
+            TransitionPath::FromOrDefault(from_or_default) => from_or_default.distinct_types(),
+            TransitionPath::FromOrElse(from_or_else) => from_or_else.distinct_types(),
+            _ => None,
+        }
+    }
+
+    /// Validates the path against the rest of the derive input, including the options of the
+    /// attribute it is in.
+    fn check_input(&self, attrs: &[Attribute], data: &Data) -> SynResult<()> {
+        match self {
+            TransitionPath::CastRef(cast_ref) => cast_ref.check_input(attrs, data),
+            TransitionPath::EqVia(eq_via) => eq_via.check_input(),
+            TransitionPath::OrdVia(ord_via) => ord_via.check_input(),
+            TransitionPath::OpsVia(ops_via) => ops_via.check_input(),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl Parse for TransitionPath {
@@ -86,7 +366,55 @@ impl Parse for TransitionPath {
             ident if ident == Self::INTO => syn::parse(tokens).map(TransitionPath::Into),
             ident if ident == Self::TRY_FROM => syn::parse(tokens).map(TransitionPath::TryFrom),
             ident if ident == Self::TRY_INTO => syn::parse(tokens).map(TransitionPath::TryInto),
-            ident => Err(SynError::new(ident.span(), "unknown parameter")),
+            ident if ident == Self::DEREF_TO => syn::parse(tokens).map(TransitionPath::DerefTo),
+            ident if ident == Self::FROM_STR => syn::parse(tokens).map(TransitionPath::FromStr),
+            ident if ident == Self::DEFAULT_VIA => {
+                syn::parse(tokens).map(TransitionPath::DefaultVia)
+            }
+            ident if ident == Self::TRY_DEFAULT_VIA => {
+                syn::parse(tokens).map(TransitionPath::TryDefaultVia)
+            }
+            ident if ident == Self::EQ_VIA => syn::parse(tokens).map(TransitionPath::EqVia),
+            ident if ident == Self::ORD_VIA => syn::parse(tokens).map(TransitionPath::OrdVia),
+            ident if ident == Self::DISPLAY_VIA => {
+                syn::parse(tokens).map(TransitionPath::DisplayVia)
+            }
+            ident if ident == Self::DEBUG_VIA => syn::parse(tokens).map(TransitionPath::DebugVia),
+            ident if ident == Self::SERDE_INTO => syn::parse(tokens).map(TransitionPath::SerdeInto),
+            ident if ident == Self::SERDE_FROM => syn::parse(tokens).map(TransitionPath::SerdeFrom),
+            ident if ident == Self::TRY_SERDE_INTO => {
+                syn::parse(tokens).map(TransitionPath::TrySerdeInto)
+            }
+            ident if ident == Self::TRY_SERDE_FROM => {
+                syn::parse(tokens).map(TransitionPath::TrySerdeFrom)
+            }
+            ident if ident == Self::OPS_VIA => syn::parse(tokens).map(TransitionPath::OpsVia),
+            ident if ident == Self::COLLECT_FROM => {
+                syn::parse(tokens).map(TransitionPath::CollectFrom)
+            }
+            ident if ident == Self::ASYNC_TRY_FROM => {
+                syn::parse(tokens).map(TransitionPath::AsyncTryFrom)
+            }
+            ident if ident == Self::CHECKED_FROM => {
+                syn::parse(tokens).map(TransitionPath::CheckedFrom)
+            }
+            ident if ident == Self::CHECKED_INTO => {
+                syn::parse(tokens).map(TransitionPath::CheckedInto)
+            }
+            ident if ident == Self::FROM_OR_DEFAULT => {
+                syn::parse(tokens).map(TransitionPath::FromOrDefault)
+            }
+            ident if ident == Self::FROM_OR_ELSE => {
+                syn::parse(tokens).map(TransitionPath::FromOrElse)
+            }
+            ident if ident == Self::CAST_REF => syn::parse(tokens).map(TransitionPath::CastRef),
+            ident => {
+                let msg = match closest_name(&ident.to_string(), Self::NAMES) {
+                    Some(name) => format!("unknown parameter, did you mean '{name}'?"),
+                    None => "unknown parameter".to_owned(),
+                };
+                Err(SynError::new(ident.span(), msg))
+            }
         }
     }
 }
@@ -95,16 +423,95 @@ impl ToTokens for TokenizablePath<'_, &TransitionPath> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match &self.path {
             TransitionPath::From(from) => {
-                TokenizablePath::new(self.ident, self.generics, from).to_tokens(tokens)
+                TokenizablePath::new(self.vis, self.ident, self.generics, from).to_tokens(tokens)
             }
             TransitionPath::Into(into) => {
-                TokenizablePath::new(self.ident, self.generics, into).to_tokens(tokens)
+                TokenizablePath::new(self.vis, self.ident, self.generics, into).to_tokens(tokens)
             }
             TransitionPath::TryFrom(try_from) => {
-                TokenizablePath::new(self.ident, self.generics, try_from).to_tokens(tokens)
+                TokenizablePath::new(self.vis, self.ident, self.generics, try_from)
+                    .to_tokens(tokens)
             }
             TransitionPath::TryInto(try_into) => {
-                TokenizablePath::new(self.ident, self.generics, try_into).to_tokens(tokens)
+                TokenizablePath::new(self.vis, self.ident, self.generics, try_into)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::DerefTo(deref_to) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, deref_to)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::FromStr(from_str) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, from_str)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::DefaultVia(default_via) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, default_via)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::TryDefaultVia(try_default_via) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, try_default_via)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::EqVia(eq_via) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, eq_via).to_tokens(tokens)
+            }
+            TransitionPath::OrdVia(ord_via) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, ord_via).to_tokens(tokens)
+            }
+            TransitionPath::DisplayVia(display_via) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, display_via)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::DebugVia(debug_via) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, debug_via)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::SerdeInto(serde_into) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, serde_into)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::SerdeFrom(serde_from) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, serde_from)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::TrySerdeInto(try_serde_into) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, try_serde_into)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::TrySerdeFrom(try_serde_from) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, try_serde_from)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::OpsVia(ops_via) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, ops_via).to_tokens(tokens)
+            }
+            TransitionPath::CollectFrom(collect_from) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, collect_from)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::AsyncTryFrom(async_try_from) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, async_try_from)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::CheckedFrom(checked_from) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, checked_from)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::CheckedInto(checked_into) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, checked_into)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::FromOrDefault(from_or_default) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, from_or_default)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::FromOrElse(from_or_else) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, from_or_else)
+                    .to_tokens(tokens)
+            }
+            TransitionPath::CastRef(cast_ref) => {
+                TokenizablePath::new(self.vis, self.ident, self.generics, cast_ref)
+                    .to_tokens(tokens)
             }
         }
     }
@@ -112,14 +519,17 @@ impl ToTokens for TokenizablePath<'_, &TransitionPath> {
 
 /// Wrapper type that aids in the tokenization of [`TransitionPath`] and its variants.
 struct TokenizablePath<'a, T> {
+    /// Visibility of the derived type, given to the generated inherent methods.
+    vis: &'a Visibility,
     ident: &'a Ident,
     generics: &'a Generics,
     path: T,
 }
 
 impl<'a, T> TokenizablePath<'a, T> {
-    fn new(ident: &'a Ident, generics: &'a Generics, path: T) -> Self {
+    fn new(vis: &'a Visibility, ident: &'a Ident, generics: &'a Generics, path: T) -> Self {
         Self {
+            vis,
             ident,
             generics,
             path,
@@ -127,60 +537,224 @@ impl<'a, T> TokenizablePath<'a, T> {
     }
 }
 
-/// Parsing helper that guarantees that there are at least two [`Type`] items in the list.
+/// Accumulates errors so that all of them get reported in a single expansion.
+#[derive(Default)]
+struct Errors(Option<SynError>);
+
+impl Errors {
+    fn push(&mut self, error: SynError) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(error),
+            None => self.0 = Some(error),
+        }
+    }
+
+    /// Returns the value of the result, recording its error if there is one.
+    fn ok<T>(&mut self, res: SynResult<T>) -> Option<T> {
+        res.map_err(|e| self.push(e)).ok()
+    }
+
+    /// Returns all the recorded errors combined, if any.
+    fn finish(self) -> SynResult<()> {
+        self.0.map_or(Ok(()), Err)
+    }
+}
+
+/// Makes sure that no two paths generate an inherent method with the same name, since the names
+/// only derive from the last segment of a type, ie. `Vec<u8>` and `Vec<u16>` or `a::Id` and
+/// `b::Id`.
 ///
 /// ```compile_fail
 /// use transitive::Transitive;
 ///
-/// struct A;
 /// #[derive(Transitive)]
-/// #[transitive(from(A))] // fails to compile, list too short
-/// struct B;
+/// #[transitive(from(Vec<u8>, Bytes, lift(Option)))] // fails to compile, both generate
+/// #[transitive(from(Vec<u16>, Bytes, lift(Option)))] // `Buffer::from_option_vec`
+/// struct Buffer(Vec<u8>);
+/// struct Bytes(Vec<u8>);
 ///
-/// impl From<A> for B {
-///     fn from(_: A) -> B {
-///         Self
+/// impl From<Vec<u8>> for Bytes {
+///     fn from(val: Vec<u8>) -> Self {
+///         Self(val)
+///     }
+/// }
+///
+/// impl From<Vec<u16>> for Bytes {
+///     fn from(val: Vec<u16>) -> Self {
+///         Self(val.into_iter().map(|v| v as u8).collect())
 ///     }
 /// }
+///
+/// impl From<Bytes> for Buffer {
+///     fn from(val: Bytes) -> Self {
+///         Self(val.0)
+///     }
+/// }
+/// ```
+fn check_methods(paths: &[TransitionPath], errors: &mut Errors) {
+    let methods = paths
+        .iter()
+        .filter_map(|path| errors.ok(path.methods()))
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let mut seen = HashMap::new();
+
+    for method in methods {
+        match seen.entry(method.to_string()) {
+            Entry::Vacant(entry) => {
+                entry.insert(method);
+            }
+            Entry::Occupied(entry) => {
+                let msg = format!("conflicting path, method `{method}` already generated");
+                let mut error = SynError::new(method.span(), msg);
+                let msg = "first path generating the method";
+                error.combine(SynError::new(entry.get().span(), msg));
+                errors.push(error);
+            }
+        }
+    }
+}
+
+/// Parses a comma separated list like [`Punctuated::parse_terminated`], but carries on past the
+/// entries that fail to parse so that their errors all get reported.
+///
+/// ```compile_fail
+/// use transitive::Transitive;
+///
+/// struct A;
+/// struct C;
+/// #[derive(Transitive)]
+/// #[transitive(try_form(A, C), into(C))] // fails to compile, both entries are reported
+/// struct B;
 /// ```
-struct AtLeastTwoTypes<T> {
-    /// First type in the list.
-    first_type: Type,
-    /// Second type in the list.
-    second_type: Type,
-    /// Remaining items in the input.
-    /// These are NOT guaranteed to be types!
-    remaining: syn::punctuated::IntoIter<T>,
+///
+/// ```compile_fail
+/// use transitive::Transitive;
+///
+/// struct A;
+/// struct C;
+/// #[derive(Transitive)]
+/// #[transitive(from(A, try fn f, C, error = A))] // fails to compile, both items are reported
+/// struct B;
+/// ```
+fn parse_all<T: Parse>(input: ParseStream) -> SynResult<Vec<SynResult<T>>> {
+    let mut items = Vec::new();
+
+    while !input.is_empty() {
+        let item = input
+            .parse()
+            .and_then(|item| match input.is_empty() || input.peek(Token![,]) {
+                true => Ok(item),
+                false => Err(input.error("expected `,`")),
+            });
+
+        // Skip the rest of a failed entry so that parsing resumes at the next one.
+        if item.is_err() {
+            while !input.is_empty() && !input.peek(Token![,]) {
+                input.parse::<TokenTree>()?;
+            }
+        }
+
+        items.push(item);
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok(items)
 }
 
-impl<T> Parse for AtLeastTwoTypes<T>
-where
-    T: Parse,
-    Option<Type>: From<T>,
-{
-    fn parse(input: ParseStream) -> SynResult<Self> {
-        let error_span = input.span();
+/// Finds the name closest to the given one, if any is close enough to be a likely typo.
+fn closest_name<'a>(name: &str, names: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    // Same threshold as the one `rustc` uses for its suggestions.
+    let max_distance = name.chars().count().max(3) / 3;
+
+    names
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
 
-        let mut remaining = Punctuated::<T, Token![,]>::parse_terminated(input)?.into_iter();
-        let first_opt = remaining.next().and_then(From::from);
-        let second_opt = remaining.next().and_then(From::from);
+/// Number of insertions, deletions, substitutions and adjacent transpositions needed to turn one
+/// string into the other, so that `try_form` is a single edit away from `try_from`.
+fn edit_distance(left: &str, right: &str) -> usize {
+    let left = left.chars().collect::<Vec<_>>();
+    let right = right.chars().collect::<Vec<_>>();
 
-        let (first_type, second_type) = match (first_opt, second_opt) {
-            (Some(first_type), Some(last_type)) => (first_type, last_type),
-            _ => return Err(SynError::new(error_span, "at least two types required")),
-        };
+    // Rows of the distances matrix, going two rows back for transpositions.
+    let mut before = Vec::new();
+    let mut prev = (0..=right.len()).collect::<Vec<_>>();
 
-        let output = Self {
-            first_type,
-            second_type,
-            remaining,
-        };
+    for i in 1..=left.len() {
+        let mut row = vec![i; right.len() + 1];
 
-        Ok(output)
+        for j in 1..=right.len() {
+            let cost = usize::from(left[i - 1] != right[j - 1]);
+            row[j] = (prev[j] + 1).min(row[j - 1] + 1).min(prev[j - 1] + cost);
+
+            if i > 1 && j > 1 && left[i - 1] == right[j - 2] && left[i - 2] == right[j - 1] {
+                row[j] = row[j].min(before[j - 2] + 1);
+            }
+        }
+
+        before = std::mem::replace(&mut prev, row);
     }
+
+    prev[right.len()]
 }
 
-/// Inserts a compile time check that the first and last types are not the same.
+/// Builds the name of a generated method by appending the snake cased name of the given type to
+/// the prefix, ie. `deref_to_inner_value` for the `deref_to` prefix and `InnerValue` type.
+/// References are named after the type they point to.
+fn method_ident(prefix: &str, mut ty: &Type) -> SynResult<Ident> {
+    while let Type::Reference(reference) = ty {
+        ty = &reference.elem;
+    }
+
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return Err(SynError::new_spanned(ty, "expected a type path"));
+    };
+
+    let Some(segment) = path.segments.last() else {
+        return Err(SynError::new_spanned(ty, "expected a type path"));
+    };
+
+    let ident = segment.ident.to_string();
+    let mut name = String::from(prefix);
+    let mut chars = ident.chars().peekable();
+    let mut prev_lower = false;
+
+    name.push('_');
+
+    while let Some(c) = chars.next() {
+        // Split on lower to upper case boundaries (`innerValue`) as well as on the last upper
+        // case letter of an acronym followed by a lower case one (`HTTPServer`).
+        let next_lower = chars.peek().is_some_and(|c| c.is_lowercase());
+        if c.is_uppercase() && !name.ends_with('_') && (prev_lower || next_lower) {
+            name.push('_');
+        }
+
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        name.extend(c.to_lowercase());
+    }
+
+    Ok(Ident::new(&name, segment.ident.span()))
+}
+
+/// Prepends the `'de` lifetime, required by `serde::Deserialize` impls, to the given generics.
+fn de_generics(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+    generics.params.insert(0, parse_quote!('de));
+    generics
+}
+
+/// Generates a single item checking at compile time, for every path of the derive, that the first
+/// and last types are not the same. The check is only evaluated when the generated conversions
+/// reference it through [`distinct_types_eval`].
 ///
 /// ```compile_fail
 /// use transitive::Transitive;
@@ -198,71 +772,111 @@ where
 ///     }
 /// }
 /// ```
-fn distinct_types_check<'a>(
-    left: &Type,
-    right: &Type,
+///
+/// ```compile_fail
+/// use transitive::Transitive;
+///
+/// struct A;
+/// type Alias = A;
+/// #[derive(Transitive)]
+/// #[transitive(from(A, Alias))] // fails to compile, `From<A> for B` would call itself
+/// struct B;
+/// ```
+///
+/// ```compile_fail
+/// use transitive::Transitive;
+///
+/// struct A;
+/// type Alias = A;
+/// #[derive(Transitive)]
+/// #[transitive(checked_from(A, Alias))] // fails to compile, first and last types are equal
+/// struct B;
+///
+/// impl From<A> for B {
+///     fn from(_: A) -> B {
+///         Self
+///     }
+/// }
+/// ```
+fn distinct_types_check(
+    pairs: &[(&Type, &Type)],
     derived: &Ident,
-    impl_generics: &ImplGenerics<'a>,
-    ty_generics: &TypeGenerics<'a>,
-    where_clause: Option<&WhereClause>,
+    generics: &Generics,
 ) -> TokenStream {
-    let turbofish = ty_generics.as_turbofish();
+    if pairs.is_empty() {
+        return TokenStream::new();
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    // We first declare an inherent constant on the wrapper type when we specifically use the
-    // `left` type.
+    // We first declare inherent constants on the wrapper type when we specifically use the
+    // `left` types. The index keeps the impls of different paths apart, as their `left` types
+    // might be the same.
     //
     // We use `(left, derived)` as the generic type for [`Checker`] because `left` might use
     // generics of `derived`, but we do not have generic components ([`ImplGenerics`],
     // [`TypeGenerics`], etc.) for other types other than `derived`, which is the derived type.
-    let checker = quote! {
-        struct Checker<T>(core::marker::PhantomData<fn() -> T>);
-
-        impl #impl_generics Checker<(#left, #derived #ty_generics)> #where_clause {
-            const FLAG: bool = false;
-        }
-    };
-
-    // We now declare a trait with a constant that has the same name as the inherent one and a
-    // blanket impl.
-    //
-    // The `left` type will also get the trait constant, but the inherent constant has priority.
-    // Because of that, if the left and right types are the same then the inherent constant gets
-    // used in the assertion twice.
-    let flagged = quote! {
-        #checker
-
-        trait Flagged {
-            const FLAG: bool;
-        }
-
-        impl<T> Flagged for Checker<T> {
-            const FLAG: bool = true;
+    let flags = pairs.iter().enumerate().map(|(i, (left, _))| {
+        quote! {
+            impl #impl_generics Checker<#i, (#left, #derived #ty_generics)> #where_clause {
+                const FLAG: bool = false;
+            }
         }
-    };
+    });
 
-    // The actual assert resides in a trait constant to allow the usage of generics in the
-    // const context. A regular const declaration would not allow the usage of generics from the
-    // outer scope.
-    //
     // The assert merely does an XOR on the checker flags. If the types are different, then
     // one flag will be the inherent constant while the other will come from the trait blanket
-    // impl.
-    //
-    // We also make sure to invoke the constant to ensure it gets compiled.
-    quote! {
-        #flagged
-
-        trait Verifier {
-            const VALID: ();
-        }
-
-        impl #impl_generics Verifier for #derived #ty_generics {
-            const VALID: () = assert!(
-                Checker::<(#left, #derived #ty_generics)>::FLAG ^ Checker::<(#right, #derived #ty_generics)>::FLAG,
+    // impl. If the left and right types are the same then the inherent constant, which has
+    // priority over the trait one, gets used in the assertion twice.
+    let asserts = pairs.iter().enumerate().map(|(i, (left, right))| {
+        quote! {
+            assert!(
+                Checker::<#i, (#left, #derived #ty_generics)>::FLAG
+                    ^ Checker::<#i, (#right, #derived #ty_generics)>::FLAG,
                 "first and last types are equal"
             );
         }
+    });
+
+    // The asserts reside in an inherent constant of the derived type to allow the usage of
+    // generics in the const context. A regular const declaration would not allow the usage of
+    // generics from the outer scope.
+    quote! {
+        const _: () = {
+            struct Checker<const I: usize, T>(core::marker::PhantomData<fn() -> T>);
+
+            #(#flags)*
+
+            trait Flagged {
+                const FLAG: bool;
+            }
 
-        let _ = #derived #turbofish::VALID;
+            impl<const I: usize, T> Flagged for Checker<I, T> {
+                const FLAG: bool = true;
+            }
+
+            impl #impl_generics #derived #ty_generics #where_clause {
+                #[doc(hidden)]
+                const __TRANSITIVE_DISTINCT_TYPES: () = {
+                    #(#asserts)*
+                };
+            }
+        };
     }
 }
+
+/// Statement evaluating the check generated by [`distinct_types_check`], which otherwise never
+/// gets compiled.
+fn distinct_types_eval(derived: &Ident, ty_generics: &TypeGenerics) -> TokenStream {
+    let turbofish = ty_generics.as_turbofish();
+    quote! {let _ = #derived #turbofish::__TRANSITIVE_DISTINCT_TYPES;}
+}
+
+/// The types to check for being distinct, unless the check is disabled.
+fn distinct_types<'a>(
+    left: &'a Type,
+    right: &'a Type,
+    enabled: bool,
+) -> Option<(&'a Type, &'a Type)> {
+    enabled.then_some((left, right))
+}
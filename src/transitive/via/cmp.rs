@@ -0,0 +1,136 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Error as SynError, Result as SynResult,
+};
+
+use super::{ViaList, ViaOption};
+use crate::transitive::{analysis::PathView, TokenizablePath};
+
+/// Path corresponding to a [`#[transitive(eq_via(..))`] path.
+pub struct EqVia(ViaList);
+
+impl Parse for EqVia {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        ViaList::parse(input).map(Self)
+    }
+}
+
+impl EqVia {
+    /// A view of the types and hops of the path.
+    pub fn view(&self) -> PathView<'_> {
+        self.0.view()
+    }
+
+    /// Applies an option of the attribute the path is in.
+    pub fn set_option(&mut self, option: &ViaOption) -> SynResult<()> {
+        set_cmp_option(&mut self.0, option)
+    }
+
+    /// Makes sure the path compares the derived type with at least one other type.
+    pub fn check_input(&self) -> SynResult<()> {
+        check_cmp_list(&self.0)
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &EqVia> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let lhs = self.path.0.convert(quote!(#name #ty_generics));
+
+        for other in self.path.0.with() {
+            let rhs = self.path.0.convert_with(quote!(other), other);
+            let expanded = quote! {
+                impl #impl_generics core::cmp::PartialEq<#other> for #name #ty_generics #where_clause {
+                    fn eq(&self, other: &#other) -> bool {
+                        core::cmp::PartialEq::eq(&#lhs, &#rhs)
+                    }
+                }
+
+                impl #impl_generics core::cmp::PartialEq<#name #ty_generics> for #other #where_clause {
+                    fn eq(&self, other: &#name #ty_generics) -> bool {
+                        core::cmp::PartialEq::eq(other, self)
+                    }
+                }
+            };
+
+            tokens.extend(expanded);
+        }
+    }
+}
+
+/// Path corresponding to a [`#[transitive(ord_via(..))`] path.
+pub struct OrdVia(ViaList);
+
+impl Parse for OrdVia {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        ViaList::parse(input).map(Self)
+    }
+}
+
+impl OrdVia {
+    /// A view of the types and hops of the path.
+    pub fn view(&self) -> PathView<'_> {
+        self.0.view()
+    }
+
+    /// Applies an option of the attribute the path is in.
+    pub fn set_option(&mut self, option: &ViaOption) -> SynResult<()> {
+        set_cmp_option(&mut self.0, option)
+    }
+
+    /// Makes sure the path compares the derived type with at least one other type.
+    pub fn check_input(&self) -> SynResult<()> {
+        check_cmp_list(&self.0)
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &OrdVia> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let lhs = self.path.0.convert(quote!(#name #ty_generics));
+
+        for other in self.path.0.with() {
+            let rhs = self.path.0.convert_with(quote!(other), other);
+            let expanded = quote! {
+                impl #impl_generics core::cmp::PartialOrd<#other> for #name #ty_generics #where_clause {
+                    fn partial_cmp(&self, other: &#other) -> core::option::Option<core::cmp::Ordering> {
+                        core::cmp::PartialOrd::partial_cmp(&#lhs, &#rhs)
+                    }
+                }
+
+                impl #impl_generics core::cmp::PartialOrd<#name #ty_generics> for #other #where_clause {
+                    fn partial_cmp(&self, other: &#name #ty_generics) -> core::option::Option<core::cmp::Ordering> {
+                        core::cmp::PartialOrd::partial_cmp(other, self).map(core::cmp::Ordering::reverse)
+                    }
+                }
+            };
+
+            tokens.extend(expanded);
+        }
+    }
+}
+
+/// Sets the types a comparison path compares the derived type with.
+fn set_cmp_option(list: &mut ViaList, option: &ViaOption) -> SynResult<()> {
+    match option {
+        ViaOption::With(key, with) => list.set_with(key, with),
+        ViaOption::Ops(key, _) => {
+            let msg = "'ops' not allowed for comparisons";
+            Err(SynError::new_spanned(key, msg))
+        }
+    }
+}
+
+/// Makes sure a comparison path compares the derived type with at least one other type.
+fn check_cmp_list(list: &ViaList) -> SynResult<()> {
+    if list.with().is_empty() {
+        let msg = "at least one type to compare with required, ie. 'with(B)'";
+        return Err(SynError::new_spanned(list.target(), msg));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Result as SynResult,
+};
+
+use super::ViaList;
+use crate::transitive::{analysis::PathView, TokenizablePath};
+
+/// Path corresponding to a [`#[transitive(display_via(..))`] path.
+pub struct DisplayVia(ViaList);
+
+impl Parse for DisplayVia {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        ViaList::parse(input).map(Self)
+    }
+}
+
+impl DisplayVia {
+    /// A view of the types and hops of the path.
+    pub fn view(&self) -> PathView<'_> {
+        self.0.view()
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &DisplayVia> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let val = self.path.0.convert(quote!(#name #ty_generics));
+
+        let expanded = quote! {
+            impl #impl_generics core::fmt::Display for #name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::Display::fmt(&#val, f)
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
+
+/// Path corresponding to a [`#[transitive(debug_via(..))`] path.
+pub struct DebugVia(ViaList);
+
+impl Parse for DebugVia {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        ViaList::parse(input).map(Self)
+    }
+}
+
+impl DebugVia {
+    /// A view of the types and hops of the path.
+    pub fn view(&self) -> PathView<'_> {
+        self.0.view()
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &DebugVia> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let val = self.path.0.convert(quote!(#name #ty_generics));
+
+        let expanded = quote! {
+            impl #impl_generics core::fmt::Debug for #name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::Debug::fmt(&#val, f)
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
@@ -0,0 +1,171 @@
+mod cmp;
+mod fmt;
+mod ops;
+
+pub use cmp::{EqVia, OrdVia};
+pub use fmt::{DebugVia, DisplayVia};
+pub use ops::OpsVia;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::Paren,
+    Error as SynError, Ident, Result as SynResult, Token, Type,
+};
+
+use crate::transitive::{analysis::PathView, infallible::TransitionInto};
+
+/// A path list leading from the derived type to a target type, ie. for
+/// `#[transitive(eq_via(B, C))]` or `#[transitive(display_via(B, C))]`, parsed and converted just
+/// like an `into` path. When converting from a reference, the derived type gets cloned unless the
+/// `ref` flag is present, in which case the first conversion is done from a reference.
+struct ViaList {
+    /// The path to the target type, which may consist of a single type.
+    path: TransitionInto,
+    /// Other types that must be converted to the target type, ie. `with(D, E)`.
+    with: Option<(Ident, Vec<Type>)>,
+}
+
+impl ViaList {
+    /// Expression converting `self`, a reference to the derived type, along the path to the
+    /// target type.
+    fn convert(&self, derived: impl ToTokens) -> TokenStream {
+        let (init, stmts) = match self.path.by_ref() {
+            Some(_) => (quote!(self), self.path.stmts(quote!(&#derived))),
+            None => (
+                quote!(core::clone::Clone::clone(self)),
+                self.path.stmts(derived),
+            ),
+        };
+
+        quote! {{
+            let val = #init;
+            #(#stmts)*
+        }}
+    }
+
+    /// Expression converting the given value of the derived type along the path to the target
+    /// type.
+    fn convert_owned(&self, val: TokenStream, derived: impl ToTokens) -> TokenStream {
+        let stmts = self.path.stmts(derived);
+
+        quote! {{
+            let val = #val;
+            #(#stmts)*
+        }}
+    }
+
+    /// Expression converting the given reference directly to the target type.
+    fn convert_with(&self, val: TokenStream, ty: &Type) -> TokenStream {
+        let target = self.target();
+        let span = Span::call_site().located_at(ty.span());
+
+        match self.path.by_ref() {
+            Some(_) => quote_spanned! {span=>
+                <#target as core::convert::From<&#ty>>::from(#val)
+            },
+            None => quote_spanned! {span=>
+                <#target as core::convert::From<#ty>>::from(core::clone::Clone::clone(#val))
+            },
+        }
+    }
+
+    /// The type that everything gets converted to.
+    fn target(&self) -> &Type {
+        self.path.last_type()
+    }
+
+    /// The types to convert to the target type along with the derived type.
+    fn with(&self) -> &[Type] {
+        self.with
+            .as_ref()
+            .map(|(_, with)| with.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Sets the types to convert to the target type along with the derived type.
+    fn set_with(&mut self, key: &Ident, with: &[Type]) -> SynResult<()> {
+        if self.with.is_some() {
+            let msg = "'with' not allowed multiple times";
+            return Err(SynError::new_spanned(key, msg));
+        }
+
+        self.with = Some((key.clone(), with.to_vec()));
+        Ok(())
+    }
+
+    /// A view of the types and hops of the path, which generates no conversion trait impl.
+    fn view(&self) -> PathView<'_> {
+        PathView {
+            generates: false,
+            ..self.path.view()
+        }
+    }
+}
+
+impl Parse for ViaList {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let output = Self {
+            path: TransitionInto::parse_via(input)?,
+            with: None,
+        };
+
+        Ok(output)
+    }
+}
+
+/// An option in a `#[transitive(..)]` attribute, applying to the paths of the attribute. Lists
+/// either the types compared with the derived type by `eq_via` and `ord_via` paths, ie.
+/// `with(D, E)`, or the operators implemented by `ops_via` paths, ie. `ops(Add, Sub)`.
+pub enum ViaOption {
+    With(Ident, Vec<Type>),
+    Ops(Ident, Vec<Type>),
+}
+
+impl ViaOption {
+    const WITH: &'static str = "with";
+    const OPS: &'static str = "ops";
+
+    /// Whether the input starts with an option instead of a path.
+    pub fn peek(input: ParseStream) -> bool {
+        // A path looks the same, so look for the exact ident followed by parentheses.
+        let fork = input.fork();
+        input.peek2(Paren)
+            && fork
+                .parse::<Ident>()
+                .is_ok_and(|ident| ident == Self::WITH || ident == Self::OPS)
+    }
+
+    /// The name of the option.
+    pub fn key(&self) -> &Ident {
+        match self {
+            Self::With(key, _) | Self::Ops(key, _) => key,
+        }
+    }
+}
+
+impl Parse for ViaOption {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let key = input.parse::<Ident>()?;
+        let content;
+        syn::parenthesized!(content in input);
+        let list = Punctuated::<Type, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .collect();
+
+        match key == Self::WITH {
+            true => Ok(Self::With(key, list)),
+            false => Ok(Self::Ops(key, list)),
+        }
+    }
+}
+
+/// Returns an error if the given flag is present in a list that does not support it.
+fn reject<T: ToTokens>(flag: Option<&T>, msg: &str) -> SynResult<()> {
+    match flag {
+        Some(flag) => Err(SynError::new_spanned(flag, msg)),
+        None => Ok(()),
+    }
+}
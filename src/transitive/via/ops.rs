@@ -0,0 +1,226 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    Error as SynError, GenericArgument, Ident, PathArguments, Result as SynResult, Type, TypePath,
+};
+
+use super::{reject, ViaList, ViaOption};
+use crate::transitive::{analysis::PathView, infallible::TransitionFrom, TokenizablePath};
+
+/// Path corresponding to a [`#[transitive(ops_via(..))`] path.
+pub struct OpsVia {
+    list: ViaList,
+    /// The path converting the result of an operator back to the derived type.
+    back: Box<TransitionFrom>,
+    /// The operators listed in the `ops(..)` option of the attribute.
+    ops: Option<Vec<Operator>>,
+}
+
+impl Parse for OpsVia {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let list = ViaList::parse(input)?;
+        reject(list.path.by_ref(), "'ref' not allowed for operators")?;
+        let back = Box::new(list.path.reversed()?);
+
+        let output = Self {
+            list,
+            back,
+            ops: None,
+        };
+
+        Ok(output)
+    }
+}
+
+impl OpsVia {
+    /// A view of the types and hops of the path.
+    pub fn view(&self) -> PathView<'_> {
+        self.list.view()
+    }
+
+    /// Applies an option of the attribute the path is in.
+    pub fn set_option(&mut self, option: &ViaOption) -> SynResult<()> {
+        match option {
+            ViaOption::With(key, _) => {
+                let msg = "'with' not allowed for operators";
+                Err(SynError::new_spanned(key, msg))
+            }
+            ViaOption::Ops(key, _) if self.ops.is_some() => {
+                let msg = "'ops' not allowed multiple times";
+                Err(SynError::new_spanned(key, msg))
+            }
+            ViaOption::Ops(_, ops) => {
+                let ops = ops.iter().map(Operator::new).collect::<SynResult<_>>()?;
+                self.ops = Some(ops);
+                Ok(())
+            }
+        }
+    }
+
+    /// Makes sure the path implements at least one operator.
+    pub fn check_input(&self) -> SynResult<()> {
+        match &self.ops {
+            Some(ops) if !ops.is_empty() => Ok(()),
+            _ => {
+                let msg = "at least one operator required, ie. 'ops(Add)'";
+                Err(SynError::new_spanned(self.list.target(), msg))
+            }
+        }
+    }
+
+    /// Expression converting the given value of the target type back along the path to the
+    /// derived type.
+    fn convert_back(&self, val: TokenStream) -> TokenStream {
+        let stmts = self.back.stmts();
+
+        quote! {{
+            let val = #val;
+            #(#stmts)*
+        }}
+    }
+}
+
+impl ToTokens for TokenizablePath<'_, &OpsVia> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let target = self.path.list.target();
+        let lhs = self
+            .path
+            .list
+            .convert_owned(quote!(self), quote!(#name #ty_generics));
+
+        for Operator {
+            trait_ident,
+            method,
+            rhs,
+        } in self.path.ops.iter().flatten()
+        {
+            let expanded = match rhs {
+                // Unary operator
+                None => {
+                    let output = self.path.convert_back(quote! {
+                        core::ops::#trait_ident::#method(lhs)
+                    });
+
+                    quote! {
+                        impl #impl_generics core::ops::#trait_ident for #name #ty_generics #where_clause {
+                            type Output = Self;
+
+                            fn #method(self) -> Self {
+                                let lhs: #target = #lhs;
+                                #output
+                            }
+                        }
+                    }
+                }
+                // Binary operator with the derived type as right hand side
+                Some(None) => {
+                    let rhs = self
+                        .path
+                        .list
+                        .convert_owned(quote!(rhs), quote!(#name #ty_generics));
+                    let output = self.path.convert_back(quote! {
+                        core::ops::#trait_ident::#method(lhs, rhs)
+                    });
+
+                    quote! {
+                        impl #impl_generics core::ops::#trait_ident for #name #ty_generics #where_clause {
+                            type Output = Self;
+
+                            fn #method(self, rhs: Self) -> Self {
+                                let lhs: #target = #lhs;
+                                let rhs: #target = #rhs;
+                                #output
+                            }
+                        }
+                    }
+                }
+                // Binary operator with a custom right hand side
+                Some(Some(rhs_ty)) => {
+                    let span = Span::call_site().located_at(rhs_ty.span());
+                    let rhs = quote_spanned! {span=>
+                        <#target as core::convert::From<#rhs_ty>>::from(rhs)
+                    };
+                    let output = self.path.convert_back(quote! {
+                        core::ops::#trait_ident::#method(lhs, rhs)
+                    });
+
+                    quote! {
+                        impl #impl_generics core::ops::#trait_ident<#rhs_ty> for #name #ty_generics #where_clause {
+                            type Output = Self;
+
+                            fn #method(self, rhs: #rhs_ty) -> Self {
+                                let lhs: #target = #lhs;
+                                let rhs: #target = #rhs;
+                                #output
+                            }
+                        }
+                    }
+                }
+            };
+
+            tokens.extend(expanded);
+        }
+    }
+}
+
+/// An operator trait from [`core::ops`] to implement.
+struct Operator {
+    trait_ident: Ident,
+    method: Ident,
+    /// [`None`] for unary operators, [`Some(None)`] for binary operators with the derived type as
+    /// right hand side, [`Some(Some(..))`] for binary operators with a custom right hand side.
+    rhs: Option<Option<Type>>,
+}
+
+impl Operator {
+    const UNARY: [&'static str; 2] = ["Neg", "Not"];
+    const BINARY: [&'static str; 10] = [
+        "Add", "Sub", "Mul", "Div", "Rem", "BitAnd", "BitOr", "BitXor", "Shl", "Shr",
+    ];
+
+    fn new(ty: &Type) -> SynResult<Self> {
+        let Type::Path(TypePath { qself: None, path }) = ty else {
+            return Err(SynError::new_spanned(ty, "expected an operator trait"));
+        };
+
+        let Some(segment) = path.segments.last() else {
+            return Err(SynError::new_spanned(ty, "expected an operator trait"));
+        };
+
+        let trait_ident = segment.ident.clone();
+        let name = trait_ident.to_string();
+        // Operator methods are the lower cased trait names, ie. `bitand` for `BitAnd`.
+        let method = format_ident!("{}", name.to_lowercase(), span = trait_ident.span());
+
+        let rhs = match &segment.arguments {
+            _ if !Self::UNARY.contains(&name.as_str())
+                && !Self::BINARY.contains(&name.as_str()) =>
+            {
+                return Err(SynError::new_spanned(ty, "unsupported operator"));
+            }
+            PathArguments::None if Self::UNARY.contains(&name.as_str()) => None,
+            PathArguments::None => Some(None),
+            PathArguments::AngleBracketed(args)
+                if args.args.len() == 1 && Self::BINARY.contains(&name.as_str()) =>
+            {
+                match &args.args[0] {
+                    GenericArgument::Type(rhs) => Some(Some(rhs.clone())),
+                    arg => return Err(SynError::new_spanned(arg, "expected a type")),
+                }
+            }
+            args => return Err(SynError::new_spanned(args, "unexpected arguments")),
+        };
+
+        let output = Self {
+            trait_ident,
+            method,
+            rhs,
+        };
+
+        Ok(output)
+    }
+}
@@ -0,0 +1,208 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream, Parser},
+    Attribute, Error as SynError, Field, Fields, FieldsNamed, Ident, Member, MetaList,
+    Result as SynResult, Token, Variant,
+};
+
+use crate::transitive::{
+    parse_all, Errors, TransitionFrom, TransitionPath, TransitiveInput, TryTransitionFrom,
+};
+
+/// Parses the `#[transitive(..)]` attributes of an enum variant.
+///
+/// The paths go through the type of the single variant field and then wrap the value in the
+/// variant, ie. `#[transitive(from(B, C))]` on `A::V(D)` results in `impl From<B> for A` as
+/// `B -> C -> D -> A::V`.
+pub fn variant_paths(variant: &Variant, errors: &mut Errors) -> Vec<TransitionPath> {
+    let attrs = transitive_attrs(&variant.attrs);
+
+    if attrs.is_empty() {
+        return Vec::new();
+    }
+
+    let mut fields = variant.fields.iter();
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        let msg = "variant must have exactly one field";
+        errors.push(SynError::new_spanned(&variant.ident, msg));
+        return Vec::new();
+    };
+
+    let ident = &variant.ident;
+    let wrap = match &field.ident {
+        Some(field) => quote!(Self::#ident { #field: val }),
+        None => quote!(Self::#ident(val)),
+    };
+
+    let mut paths = Vec::new();
+
+    for item in parse_items(attrs, errors) {
+        match item {
+            FieldItem::Path(list) => {
+                paths.extend(errors.ok(wrapped_path(list, field, wrap.clone())))
+            }
+            FieldItem::Default(flag) => {
+                let msg = "'default' not allowed on variants";
+                errors.push(SynError::new_spanned(flag, msg));
+            }
+        }
+    }
+
+    paths
+}
+
+/// Parses the `#[transitive(..)]` attributes of the fields of a struct.
+///
+/// The paths go through the type of the field they are placed on and then build the struct,
+/// ie. `#[transitive(from(B, C))]` on the `d: D` field of `A` results in `impl From<B> for A` as
+/// `B -> C -> D -> A { d }`. Every other field must be marked with `#[transitive(default)]` and
+/// gets filled through [`Default`].
+pub fn field_paths(fields: &Fields, errors: &mut Errors) -> Vec<TransitionPath> {
+    let mut lists = Vec::new();
+    let mut defaults = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let mut is_default = false;
+
+        for item in parse_items(transitive_attrs(&field.attrs), errors) {
+            match item {
+                FieldItem::Path(list) => lists.push((index, list)),
+                FieldItem::Default(flag) if is_default => {
+                    let msg = "'default' not allowed multiple times";
+                    errors.push(SynError::new_spanned(flag, msg));
+                }
+                FieldItem::Default(_) => is_default = true,
+            }
+        }
+
+        defaults.push(is_default);
+    }
+
+    let fields = fields.iter().collect::<Vec<_>>();
+    let mut paths = Vec::with_capacity(lists.len());
+
+    for (index, list) in lists {
+        let mut others = Vec::new();
+        let mut missing_default = false;
+
+        for (i, field) in fields.iter().enumerate() {
+            match (i == index, defaults[i]) {
+                (true, _) => (),
+                (false, true) => others.push(member(i, field)),
+                (false, false) => {
+                    let msg = "other fields must be marked with `#[transitive(default)]`";
+                    errors.push(SynError::new_spanned(field, msg));
+                    missing_default = true;
+                }
+            }
+        }
+
+        if missing_default {
+            continue;
+        }
+
+        let field = fields[index];
+        let member = member(index, field);
+        let wrap = quote!(Self { #member: val, #(#others: core::default::Default::default()),* });
+        paths.extend(errors.ok(wrapped_path(list, field, wrap)));
+    }
+
+    paths
+}
+
+/// Reports the `#[transitive(..)]` attributes on the fields of a union, which cannot be built
+/// from a single field through a struct literal like the fields of a struct.
+///
+/// ```compile_fail
+/// use transitive::Transitive;
+///
+/// #[derive(Transitive)]
+/// union A {
+///     #[transitive(from(u8))] // fails to compile, field paths not allowed on unions
+///     b: u16,
+/// }
+/// ```
+pub fn union_fields(fields: &FieldsNamed, errors: &mut Errors) {
+    for attr in fields.named.iter().flat_map(|f| transitive_attrs(&f.attrs)) {
+        let msg = "field paths not allowed on unions";
+        errors.push(SynError::new_spanned(attr, msg));
+    }
+}
+
+/// The member naming the field in a struct literal, ie. `0` for the first field of a tuple struct.
+fn member(index: usize, field: &Field) -> Member {
+    match &field.ident {
+        Some(ident) => Member::from(ident.clone()),
+        None => Member::from(index),
+    }
+}
+
+/// Parses the items of the given attributes, recording the errors of the ones that fail to parse.
+fn parse_items(attrs: Vec<&Attribute>, errors: &mut Errors) -> Vec<FieldItem> {
+    let mut items = Vec::new();
+
+    for attr in attrs {
+        if let Some(attr_items) = errors.ok(attr.parse_args_with(parse_all::<FieldItem>)) {
+            items.extend(attr_items.into_iter().filter_map(|item| errors.ok(item)));
+        }
+    }
+
+    items
+}
+
+/// Filters the `#[transitive(..)]` attributes.
+fn transitive_attrs(attrs: &[Attribute]) -> Vec<&Attribute> {
+    attrs
+        .iter()
+        .filter(|a| a.path().is_ident(TransitiveInput::ATTR_NAME))
+        .collect()
+}
+
+/// Parses a path going through the type of the field and ending with the `wrap` expression.
+fn wrapped_path(list: MetaList, field: &Field, wrap: TokenStream) -> SynResult<TransitionPath> {
+    let MetaList { path, tokens, .. } = list;
+    let field = field.ty.clone();
+
+    let parser = |input: ParseStream| match path.require_ident()? {
+        ident if ident == TransitionPath::FROM => {
+            TransitionFrom::parse_wrapped(input, field, wrap).map(TransitionPath::From)
+        }
+        ident if ident == TransitionPath::TRY_FROM => {
+            TryTransitionFrom::parse_wrapped(input, field, wrap).map(TransitionPath::TryFrom)
+        }
+        ident => {
+            let msg = "only 'from' and 'try_from' allowed on variants and fields";
+            Err(SynError::new(ident.span(), msg))
+        }
+    };
+
+    parser.parse2(tokens)
+}
+
+/// An item in a `#[transitive(..)]` attribute placed on a variant or a field.
+enum FieldItem {
+    Path(MetaList),
+    /// Marks a field to be filled through [`Default`] when the struct gets built from another
+    /// field.
+    Default(Ident),
+}
+
+impl FieldItem {
+    const DEFAULT: &'static str = "default";
+}
+
+impl Parse for FieldItem {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let fork = input.fork();
+        // A flag is a lone ident, so it must be followed by a comma or nothing at all
+        let is_default = fork
+            .parse::<Ident>()
+            .is_ok_and(|ident| ident == Self::DEFAULT && (fork.is_empty() || fork.peek(Token![,])));
+
+        match is_default {
+            true => input.parse().map(Self::Default),
+            false => input.parse().map(Self::Path),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+};
+
+use transitive::Transitive;
+use transitive_async::AsyncTryFrom;
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(async_try_from(u32, Id, plain Key, error = NotFound))] // impl AsyncTryFrom<u32> for User
+struct User(u32);
+struct Id(u32);
+struct Key(u32);
+
+#[derive(Debug, PartialEq)]
+struct NotFound;
+
+impl From<std::convert::Infallible> for NotFound {
+    fn from(val: std::convert::Infallible) -> Self {
+        match val {}
+    }
+}
+
+impl AsyncTryFrom<u32> for Id {
+    type Error = NotFound;
+
+    async fn async_try_from(val: u32) -> Result<Self, Self::Error> {
+        Ok(Self(val))
+    }
+}
+
+impl TryFrom<Id> for Key {
+    type Error = NotFound;
+
+    fn try_from(val: Id) -> Result<Self, Self::Error> {
+        match val.0 {
+            0 => Err(NotFound),
+            id => Ok(Self(id)),
+        }
+    }
+}
+
+impl AsyncTryFrom<Key> for User {
+    type Error = NotFound;
+
+    async fn async_try_from(val: Key) -> Result<Self, Self::Error> {
+        Ok(Self(val.0))
+    }
+}
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(
+    async_try_from(u32, User),
+    trait = FetchFrom,
+    method = fetch_from
+)] // impl FetchFrom<u32> for Profile
+struct Profile(User);
+
+trait FetchFrom<T>: Sized {
+    type Error;
+
+    async fn fetch_from(val: T) -> Result<Self, Self::Error>;
+}
+
+impl FetchFrom<u32> for User {
+    type Error = NotFound;
+
+    async fn fetch_from(val: u32) -> Result<Self, Self::Error> {
+        User::async_try_from(val).await
+    }
+}
+
+impl FetchFrom<User> for Profile {
+    type Error = NotFound;
+
+    async fn fetch_from(val: User) -> Result<Self, Self::Error> {
+        Ok(Self(val))
+    }
+}
+
+/// Polls a future that never waits to completion.
+fn block_on<F: Future>(fut: F) -> F::Output {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(|_| RAW, |_| (), |_| (), |_| ());
+    const RAW: RawWaker = RawWaker::new(std::ptr::null(), &VTABLE);
+
+    let waker = unsafe { Waker::from_raw(RAW) };
+    let mut cx = Context::from_waker(&waker);
+
+    match pin!(fut).poll(&mut cx) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("future is not ready"),
+    }
+}
+
+#[test]
+pub fn test_async_try_from() {
+    assert_eq!(block_on(User::async_try_from(1)), Ok(User(1)));
+    assert_eq!(block_on(User::async_try_from(0)), Err(NotFound));
+
+    assert_eq!(block_on(Profile::fetch_from(2)), Ok(Profile(User(2))));
+    assert_eq!(block_on(Profile::fetch_from(0)), Err(NotFound));
+}
@@ -0,0 +1,36 @@
+use std::marker::PhantomData;
+
+use transitive::Transitive;
+
+#[derive(Debug, PartialEq, Transitive)]
+#[repr(transparent)]
+#[transitive(cast_ref(u64))] // fn cast_ref_u64(&self) -> &u64, fn cast_slice_u64(&[Id]) -> &[u64]
+struct Id(u64);
+
+#[derive(Debug, PartialEq, Transitive)]
+#[repr(transparent)]
+#[transitive(cast_ref(Id, u64))] // fn cast_ref_u64(&self) -> &u64, fn cast_slice_u64(&[UserId]) -> &[u64]
+struct UserId(Id);
+
+#[derive(Transitive)]
+#[repr(transparent)]
+#[transitive(cast_ref(UserId, Id, u64))] // fn cast_ref_u64(&self) -> &u64, fn cast_slice_u64(&[Tagged<T>]) -> &[u64]
+struct Tagged<T> {
+    id: UserId,
+    tag: PhantomData<T>,
+}
+
+#[test]
+pub fn test_cast_ref() {
+    let ids = [UserId(Id(1)), UserId(Id(2))];
+    assert_eq!(UserId::cast_slice_u64(&ids), &[1, 2]);
+    assert_eq!(ids[1].cast_ref_u64(), &2);
+    assert_eq!(AsRef::<u64>::as_ref(&ids[0]), &1);
+
+    let tagged = [Tagged::<()> {
+        id: UserId(Id(3)),
+        tag: PhantomData,
+    }];
+    assert_eq!(Tagged::cast_slice_u64(&tagged), &[3]);
+    assert_eq!(tagged[0].cast_ref_u64(), &3);
+}
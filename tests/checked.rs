@@ -0,0 +1,63 @@
+use transitive::Transitive;
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(checked_from(&'static str, try fn str::parse::<u32>, u32, Percent))] // fn checked_from_str(&str) -> Option<Level>
+#[transitive(checked_into(Percent, u8))] // fn checked_into_u8(self) -> Option<u8>
+struct Level(u8);
+struct Percent(u8);
+
+// The error types have no common conversion target.
+struct TooLarge;
+struct NotPercent;
+
+impl TryFrom<u32> for Percent {
+    type Error = NotPercent;
+
+    fn try_from(val: u32) -> Result<Self, Self::Error> {
+        match val {
+            0..=100 => Ok(Self(val as u8)),
+            _ => Err(NotPercent),
+        }
+    }
+}
+
+impl TryFrom<Percent> for Level {
+    type Error = TooLarge;
+
+    fn try_from(val: Percent) -> Result<Self, Self::Error> {
+        match val.0 {
+            0..=10 => Ok(Self(val.0)),
+            _ => Err(TooLarge),
+        }
+    }
+}
+
+impl TryFrom<Level> for Percent {
+    type Error = NotPercent;
+
+    fn try_from(val: Level) -> Result<Self, Self::Error> {
+        Ok(Self(val.0 * 10))
+    }
+}
+
+impl TryFrom<Percent> for u8 {
+    type Error = TooLarge;
+
+    fn try_from(val: Percent) -> Result<Self, Self::Error> {
+        match val.0 {
+            0..=50 => Ok(val.0),
+            _ => Err(TooLarge),
+        }
+    }
+}
+
+#[test]
+pub fn test_checked() {
+    assert_eq!(Level::checked_from_str("7"), Some(Level(7)));
+    assert_eq!(Level::checked_from_str("seven"), None);
+    assert_eq!(Level::checked_from_str("70"), None);
+    assert_eq!(Level::checked_from_str("700"), None);
+
+    assert_eq!(Level(5).checked_into_u8(), Some(50));
+    assert_eq!(Level(6).checked_into_u8(), None);
+}
@@ -0,0 +1,73 @@
+use std::marker::PhantomData;
+
+use transitive::Transitive;
+
+#[derive(Clone, Transitive)]
+#[transitive(eq_via(Millimeters), ord_via(Millimeters), with(Feet))] // impl PartialEq<Feet> and PartialOrd<Feet> for Meters and vice versa
+struct Meters(u64);
+
+#[derive(Clone)]
+struct Feet(u64);
+
+#[derive(PartialEq, PartialOrd)]
+struct Millimeters(u64);
+
+impl From<Meters> for Millimeters {
+    fn from(value: Meters) -> Self {
+        Self(value.0 * 1000)
+    }
+}
+
+impl From<Feet> for Millimeters {
+    fn from(value: Feet) -> Self {
+        Self(value.0 * 305)
+    }
+}
+
+#[derive(Transitive)]
+#[transitive(
+    eq_via(Centimeters, Millimeters, ref),
+    ord_via(Centimeters, Millimeters, ref),
+    with(Feet, Inches)
+)] // by reference
+struct Z<T>(u64, PhantomData<T>);
+
+struct Centimeters(u64);
+struct Inches(u64);
+
+impl<T> From<&Z<T>> for Centimeters {
+    fn from(value: &Z<T>) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<Centimeters> for Millimeters {
+    fn from(value: Centimeters) -> Self {
+        Self(value.0 * 10)
+    }
+}
+
+impl From<&Feet> for Millimeters {
+    fn from(value: &Feet) -> Self {
+        Self(value.0 * 305)
+    }
+}
+
+impl From<&Inches> for Millimeters {
+    fn from(value: &Inches) -> Self {
+        Self(value.0 * 25)
+    }
+}
+
+#[test]
+pub fn test_cmp_via() {
+    assert!(Meters(61) == Feet(200));
+    assert!(Feet(200) == Meters(61));
+    assert!(Meters(1) < Feet(4));
+    assert!(Feet(4) > Meters(1));
+
+    assert!(Z::<()>(305, PhantomData) == Feet(10));
+    assert!(Inches(12) == Z::<()>(30, PhantomData));
+    assert!(Z::<()>(30, PhantomData) < Inches(13));
+    assert!(Inches(13) > Z::<()>(30, PhantomData));
+}
@@ -0,0 +1,86 @@
+use std::{fmt::Display, rc::Rc};
+
+use transitive::Transitive;
+
+trait Handler {
+    fn handle(&self) -> String;
+}
+
+#[derive(Transitive)]
+#[transitive(into(Box<Echo>, coerce Box<dyn Handler>, Registered))] // impl From<Echo> for Registered
+#[transitive(try_into(Box<Echo>, coerce Box<dyn Handler>, Checked, error = Rejected))] // impl TryFrom<Echo> for Checked
+#[transitive(checked_into(Rc<Echo>, coerce Rc<dyn Display>))] // fn checked_into_rc(self) -> Option<Rc<dyn Display>>
+struct Echo(&'static str);
+
+#[derive(Transitive)]
+#[transitive(from(Box<Echo>, coerce Box<dyn Handler>))] // impl From<Box<Echo>> for Registered
+struct Registered(Box<dyn Handler>);
+
+#[derive(Transitive)]
+#[transitive(try_from(Box<Echo>, coerce Box<dyn Handler>))] // impl TryFrom<Box<Echo>> for Checked
+struct Checked(Registered);
+
+#[derive(Debug)]
+struct Rejected;
+
+impl Handler for Echo {
+    fn handle(&self) -> String {
+        self.0.to_owned()
+    }
+}
+
+impl Display for Echo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl From<Box<dyn Handler>> for Registered {
+    fn from(val: Box<dyn Handler>) -> Self {
+        Self(val)
+    }
+}
+
+impl TryFrom<Box<dyn Handler>> for Checked {
+    type Error = Rejected;
+
+    fn try_from(val: Box<dyn Handler>) -> Result<Self, Self::Error> {
+        match val.handle().is_empty() {
+            true => Err(Rejected),
+            false => Ok(Self(Registered(val))),
+        }
+    }
+}
+
+impl From<std::convert::Infallible> for Rejected {
+    fn from(val: std::convert::Infallible) -> Self {
+        match val {}
+    }
+}
+
+#[test]
+pub fn test_coerce() {
+    assert_eq!(Registered::from(Echo("ping")).0.handle(), "ping");
+
+    assert_eq!(
+        Checked::try_from(Echo("pong")).unwrap().0 .0.handle(),
+        "pong"
+    );
+    assert!(Checked::try_from(Echo("")).is_err());
+
+    assert_eq!(
+        Registered::from(Box::new(Echo("boxed"))).0.handle(),
+        "boxed"
+    );
+    assert_eq!(
+        Checked::try_from(Box::new(Echo("boxed")))
+            .unwrap()
+            .0
+             .0
+            .handle(),
+        "boxed"
+    );
+    assert!(Checked::try_from(Box::new(Echo(""))).is_err());
+
+    assert_eq!(Echo("rc").checked_into_rc().unwrap().to_string(), "rc");
+}
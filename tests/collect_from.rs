@@ -0,0 +1,97 @@
+use std::collections::BTreeSet;
+
+use transitive::Transitive;
+
+#[derive(Debug, Default, PartialEq, Transitive)]
+#[transitive(collect_from(u8, Raw, Id))] // impl FromIterator<u8> and Extend<u8> for Ids
+struct Ids(Vec<Id>);
+
+#[derive(Transitive)]
+#[transitive(collect_from(u8, Raw, Id))] // impl FromIterator<u8> and Extend<u8> for Z<T>
+struct Z<T: Ord + From<Id>>(BTreeSet<T>);
+
+#[derive(Transitive)]
+#[transitive(collect_from(Box<Square>, coerce Box<dyn Shape>))] // impl FromIterator<Box<Square>> and Extend<Box<Square>> for Shapes
+struct Shapes(Vec<Box<dyn Shape>>);
+
+trait Shape {
+    fn area(&self) -> u32;
+}
+
+struct Square(u32);
+
+impl Shape for Square {
+    fn area(&self) -> u32 {
+        self.0 * self.0
+    }
+}
+
+struct Raw(u8);
+
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
+struct Id(u16);
+
+impl From<u8> for Raw {
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Raw> for Id {
+    fn from(value: Raw) -> Self {
+        Self(value.0.into())
+    }
+}
+
+impl FromIterator<Id> for Ids {
+    fn from_iter<T: IntoIterator<Item = Id>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Id> for Ids {
+    fn extend<T: IntoIterator<Item = Id>>(&mut self, iter: T) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T: Ord + From<Id>> FromIterator<Id> for Z<T> {
+    fn from_iter<I: IntoIterator<Item = Id>>(iter: I) -> Self {
+        Self(iter.into_iter().map(From::from).collect())
+    }
+}
+
+impl<T: Ord + From<Id>> Extend<Id> for Z<T> {
+    fn extend<I: IntoIterator<Item = Id>>(&mut self, iter: I) {
+        self.0.extend(iter.into_iter().map(From::from))
+    }
+}
+
+impl FromIterator<Box<dyn Shape>> for Shapes {
+    fn from_iter<T: IntoIterator<Item = Box<dyn Shape>>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Box<dyn Shape>> for Shapes {
+    fn extend<T: IntoIterator<Item = Box<dyn Shape>>>(&mut self, iter: T) {
+        self.0.extend(iter)
+    }
+}
+
+#[test]
+pub fn test_collect_from() {
+    let mut ids: Ids = [1u8, 2].into_iter().collect();
+    assert_eq!(ids, Ids(vec![Id(1), Id(2)]));
+
+    ids.extend([3u8]);
+    assert_eq!(ids, Ids(vec![Id(1), Id(2), Id(3)]));
+
+    let z: Z<Id> = [2u8, 1].into_iter().collect();
+    assert_eq!(z.0.into_iter().collect::<Vec<_>>(), vec![Id(1), Id(2)]);
+
+    let mut shapes: Shapes = [Box::new(Square(2))].into_iter().collect();
+    shapes.extend([Box::new(Square(3))]);
+    let areas = shapes.0.iter().map(|s| s.area()).collect::<Vec<_>>();
+    assert_eq!(areas, vec![4, 9]);
+}
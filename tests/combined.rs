@@ -1,3 +1,10 @@
+// Lints of newer toolchains, unknown to the minimum supported one.
+#![allow(
+    unknown_lints,
+    clippy::duplicated_attributes,
+    clippy::infallible_try_from
+)]
+
 mod macros;
 
 use transitive::Transitive;
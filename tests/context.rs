@@ -0,0 +1,148 @@
+use transitive::Transitive;
+
+pub trait ConvertWith<T, Ctx> {
+    fn convert(val: T, ctx: &Ctx) -> Self;
+}
+
+pub trait TryConvertWith<T, Ctx>: Sized {
+    type Error;
+
+    fn try_convert(val: T, ctx: &Ctx) -> Result<Self, Self::Error>;
+}
+
+/// Interns names, handing out their index.
+struct Interner(Vec<&'static str>);
+
+impl Interner {
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.0.iter().position(|n| *n == name)
+    }
+}
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(
+    from(Name, Symbol, plain Index),
+    into(Index, plain usize),
+    with_context(interner: &Interner),
+    trait = ConvertWith,
+    method = convert
+)] // impl ConvertWith<Name, Interner> for Id and ConvertWith<Id, Interner> for usize
+struct Id(usize);
+#[derive(Debug, PartialEq)]
+struct Name(&'static str);
+struct Symbol(usize);
+struct Index(usize);
+
+impl ConvertWith<Name, Interner> for Symbol {
+    fn convert(val: Name, ctx: &Interner) -> Self {
+        Self(ctx.index_of(val.0).unwrap())
+    }
+}
+
+impl From<Symbol> for Index {
+    fn from(val: Symbol) -> Self {
+        Self(val.0)
+    }
+}
+
+impl ConvertWith<Index, Interner> for Id {
+    fn convert(val: Index, _: &Interner) -> Self {
+        Self(val.0)
+    }
+}
+
+impl ConvertWith<Id, Interner> for Index {
+    fn convert(val: Id, ctx: &Interner) -> Self {
+        Self(val.0 + ctx.0.len())
+    }
+}
+
+impl From<Index> for usize {
+    fn from(val: Index) -> Self {
+        val.0
+    }
+}
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(
+    try_from(&'static str, plain Name, error = Unknown),
+    with_context(interner: &Interner),
+    trait = TryConvertWith,
+    method = try_convert
+)] // impl TryConvertWith<&'static str, Interner> for Known
+struct Known(usize);
+
+#[derive(Debug, PartialEq)]
+struct Unknown;
+
+impl From<std::convert::Infallible> for Unknown {
+    fn from(val: std::convert::Infallible) -> Self {
+        match val {}
+    }
+}
+
+impl From<&'static str> for Name {
+    fn from(val: &'static str) -> Self {
+        Self(val)
+    }
+}
+
+impl TryConvertWith<Name, Interner> for Known {
+    type Error = Unknown;
+
+    fn try_convert(val: Name, ctx: &Interner) -> Result<Self, Self::Error> {
+        ctx.index_of(val.0).map(Self).ok_or(Unknown)
+    }
+}
+
+pub trait ConvertWithMut<T, Ctx> {
+    fn convert_mut(val: T, ctx: &mut Ctx) -> Self;
+}
+
+/// Counts the hops that went through it.
+struct Counter(usize);
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(
+    from(u8, Name, Index),
+    with_context(counter: &mut Counter),
+    trait = ConvertWithMut,
+    method = convert_mut
+)] // impl ConvertWithMut<u8, Counter> for Tally
+struct Tally(usize);
+
+impl ConvertWithMut<u8, Counter> for Name {
+    fn convert_mut(_: u8, ctx: &mut Counter) -> Self {
+        ctx.0 += 1;
+        Self("tally")
+    }
+}
+
+impl ConvertWithMut<Name, Counter> for Index {
+    fn convert_mut(val: Name, ctx: &mut Counter) -> Self {
+        ctx.0 += 1;
+        Self(val.0.len())
+    }
+}
+
+impl ConvertWithMut<Index, Counter> for Tally {
+    fn convert_mut(val: Index, ctx: &mut Counter) -> Self {
+        ctx.0 += 1;
+        Self(val.0)
+    }
+}
+
+#[test]
+pub fn test_context() {
+    let interner = Interner(vec!["foo", "bar"]);
+
+    assert_eq!(Id::convert(Name("bar"), &interner), Id(1));
+    assert_eq!(usize::convert(Id(1), &interner), 3);
+
+    assert_eq!(Known::try_convert("foo", &interner), Ok(Known(0)));
+    assert_eq!(Known::try_convert("baz", &interner), Err(Unknown));
+
+    let mut counter = Counter(0);
+    assert_eq!(Tally::convert_mut(1, &mut counter), Tally(5));
+    assert_eq!(counter.0, 3);
+}
@@ -0,0 +1,131 @@
+use transitive::Transitive;
+
+mod proto {
+    pub trait FromProto<T> {
+        fn from_proto(val: T) -> Self;
+    }
+
+    pub trait TryFromRow<T>: Sized {
+        type Err;
+
+        fn try_from_row(val: T) -> Result<Self, Self::Err>;
+    }
+}
+
+use proto::{FromProto, TryFromRow};
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(from(D, C, B), trait = proto::FromProto, method = from_proto)] // impl FromProto<D> for A
+#[transitive(into(B, C), trait = FromProto, method = from_proto)] // impl FromProto<A> for C
+#[transitive(from(C, B))] // impl From<C> for A, unaffected by the attributes above
+struct A;
+#[derive(Debug, PartialEq)]
+struct B;
+#[derive(Debug, PartialEq)]
+struct C;
+#[derive(Debug, PartialEq)]
+struct D;
+
+impl FromProto<D> for C {
+    fn from_proto(_: D) -> Self {
+        Self
+    }
+}
+
+impl FromProto<C> for B {
+    fn from_proto(_: C) -> Self {
+        Self
+    }
+}
+
+impl FromProto<B> for A {
+    fn from_proto(_: B) -> Self {
+        Self
+    }
+}
+
+impl FromProto<A> for B {
+    fn from_proto(_: A) -> Self {
+        Self
+    }
+}
+
+impl FromProto<B> for C {
+    fn from_proto(_: B) -> Self {
+        Self
+    }
+}
+
+impl From<C> for B {
+    fn from(_: C) -> Self {
+        Self
+    }
+}
+
+impl From<B> for A {
+    fn from(_: B) -> Self {
+        Self
+    }
+}
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(
+    try_from(u8, Even),
+    try_into(Even, u8),
+    trait = TryFromRow,
+    method = try_from_row,
+    error_name = Err
+)] // impl TryFromRow<u8> for Row and TryFromRow<Row> for u8
+struct Row(u8);
+#[derive(Debug, PartialEq)]
+struct Even(u8);
+
+#[derive(Debug, PartialEq)]
+struct RowError;
+
+impl TryFromRow<u8> for Even {
+    type Err = RowError;
+
+    fn try_from_row(val: u8) -> Result<Self, Self::Err> {
+        match val % 2 {
+            0 => Ok(Self(val)),
+            _ => Err(RowError),
+        }
+    }
+}
+
+impl TryFromRow<Even> for Row {
+    type Err = RowError;
+
+    fn try_from_row(val: Even) -> Result<Self, Self::Err> {
+        Ok(Self(val.0))
+    }
+}
+
+impl TryFromRow<Row> for Even {
+    type Err = RowError;
+
+    fn try_from_row(val: Row) -> Result<Self, Self::Err> {
+        Even::try_from_row(val.0)
+    }
+}
+
+impl TryFromRow<Even> for u8 {
+    type Err = RowError;
+
+    fn try_from_row(val: Even) -> Result<Self, Self::Err> {
+        Ok(val.0)
+    }
+}
+
+#[test]
+pub fn test_custom_trait() {
+    assert_eq!(A::from_proto(D), A);
+    assert_eq!(C::from_proto(A), C);
+    assert_eq!(A::from(C), A);
+
+    assert_eq!(Row::try_from_row(2), Ok(Row(2)));
+    assert_eq!(Row::try_from_row(3), Err(RowError));
+    assert_eq!(u8::try_from_row(Row(4)), Ok(4));
+    assert_eq!(u8::try_from_row(Row(5)), Err(RowError));
+}
@@ -0,0 +1,136 @@
+mod macros;
+
+use std::marker::PhantomData;
+
+use transitive::Transitive;
+
+#[derive(Transitive)]
+#[transitive(default_via(RawConfig, Validated))] // impl Default for Config
+struct Config(u8);
+
+#[derive(Transitive)]
+#[transitive(default_via(RawConfig, Validated))] // impl Default for Z<T>
+struct Z<T>(PhantomData<T>);
+
+#[derive(Transitive)]
+#[transitive(default_via(RawConfig))] // impl Default for Single
+struct Single(u8);
+
+#[derive(Default)]
+struct RawConfig(u8);
+struct Validated(u8);
+
+impl From<RawConfig> for Validated {
+    fn from(value: RawConfig) -> Self {
+        Self(value.0 + 1)
+    }
+}
+
+impl From<Validated> for Config {
+    fn from(value: Validated) -> Self {
+        Self(value.0 + 1)
+    }
+}
+
+impl From<RawConfig> for Single {
+    fn from(value: RawConfig) -> Self {
+        Self(value.0 + 5)
+    }
+}
+
+impl<T> From<Validated> for Z<T> {
+    fn from(_value: Validated) -> Self {
+        Self(PhantomData)
+    }
+}
+
+mod try_default_via {
+    use super::*;
+
+    #[derive(Transitive)]
+    #[transitive(try_default_via(D, C, B))] // impl Default for A
+    struct A;
+
+    #[derive(Transitive)]
+    #[transitive(try_default_via(D, C, error = ConvErr))] // impl Default for B, with custom error
+    struct B;
+
+    #[derive(Default)]
+    struct C;
+    #[derive(Default)]
+    struct D;
+
+    #[derive(Transitive)]
+    #[transitive(try_default_via(C))] // impl Default for F
+    struct F;
+
+    impl_try_from!(C to F err ErrCB);
+
+    #[derive(Transitive)]
+    #[transitive(try_default_via(D, C))] // impl Default for E, always panics
+    struct E;
+
+    struct ConvErr;
+    struct ErrDC;
+    struct ErrCB;
+
+    #[derive(Transitive)]
+    #[transitive(from(ErrDC, ErrCB))] // impl From<ErrDC> for ErrBA
+    struct ErrBA;
+
+    impl From<ErrDC> for ErrCB {
+        fn from(_value: ErrDC) -> Self {
+            Self
+        }
+    }
+
+    impl From<ErrCB> for ErrBA {
+        fn from(_value: ErrCB) -> Self {
+            Self
+        }
+    }
+
+    impl From<ErrDC> for ConvErr {
+        fn from(_value: ErrDC) -> Self {
+            Self
+        }
+    }
+
+    impl From<ErrCB> for ConvErr {
+        fn from(_value: ErrCB) -> Self {
+            Self
+        }
+    }
+
+    impl_try_from!(B to A err ErrBA);
+    impl_try_from!(C to B err ErrCB);
+    impl_try_from!(D to C err ErrDC);
+
+    impl TryFrom<C> for E {
+        type Error = ErrCB;
+
+        fn try_from(_value: C) -> Result<Self, Self::Error> {
+            Err(ErrCB)
+        }
+    }
+
+    #[test]
+    pub fn test_try_default_via() {
+        let _: A = Default::default();
+        let _: B = Default::default();
+        let _: F = Default::default();
+    }
+
+    #[test]
+    #[should_panic = "failed to convert the default `D` into `E`"]
+    pub fn test_try_default_via_panic() {
+        let _: E = Default::default();
+    }
+}
+
+#[test]
+pub fn test_default_via() {
+    assert_eq!(Config::default().0, 2);
+    let _ = Z::<()>::default();
+    assert_eq!(Single::default().0, 5);
+}
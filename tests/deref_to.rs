@@ -0,0 +1,90 @@
+use std::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
+
+use transitive::Transitive;
+
+#[derive(Transitive)]
+#[transitive(deref_to(Shared, Arc<Inner>, Inner))] // fn deref_to_inner(&self) -> &Inner
+struct Handle(Shared);
+
+#[derive(Transitive)]
+#[transitive(deref_to(Inner, u8, mut, as_ref))] // fn deref_to_u8(&self) -> &u8, _mut and AsRef
+struct Wrapper(Inner);
+
+struct Shared(Arc<Inner>);
+struct Inner(u8);
+
+impl Deref for Handle {
+    type Target = Shared;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Deref for Shared {
+    type Target = Arc<Inner>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Deref for Wrapper {
+    type Target = Inner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Deref for Inner {
+    type Target = u8;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Inner {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[derive(Transitive)]
+#[transitive(deref_to(Inner, u8, as_ref))] // fn deref_to_u8(&self) -> &u8 for Z<'a, T>
+struct Z<'a, T>(&'a Inner, PhantomData<T>);
+
+impl<T> Deref for Z<'_, T> {
+    type Target = Inner;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+#[test]
+pub fn test_deref_to() {
+    let handle = Handle(Shared(Arc::new(Inner(1))));
+    assert_eq!(handle.deref_to_inner().0, 1);
+
+    let mut wrapper = Wrapper(Inner(2));
+    *wrapper.deref_to_u8_mut() += 1;
+    assert_eq!(*wrapper.deref_to_u8(), 3);
+    assert_eq!(*AsRef::<u8>::as_ref(&wrapper), 3);
+
+    let inner = Inner(4);
+    let z = Z::<()>(&inner, PhantomData);
+    assert_eq!(*z.deref_to_u8(), 4);
+    assert_eq!(*AsRef::<u8>::as_ref(&z), 4);
+}
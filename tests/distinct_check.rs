@@ -0,0 +1,57 @@
+use std::marker::PhantomData;
+
+use transitive::Transitive;
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(from((u8, u8), [u8; 2]))] // impl From<(u8, u8)> for Pair
+#[transitive(into([u8; 2], (u8, u8), no_distinct_check))] // impl From<Pair> for (u8, u8)
+struct Pair([u8; 2]);
+
+impl From<[u8; 2]> for Pair {
+    fn from(val: [u8; 2]) -> Self {
+        Self(val)
+    }
+}
+
+impl From<Pair> for [u8; 2] {
+    fn from(val: Pair) -> Self {
+        val.0
+    }
+}
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(from(u8, Wrapper<u8>, Meters<T>))] // impl<T> From<u8> for Length<T>
+#[transitive(checked_from(u8, Wrapper<u8>, Meters<T>))] // fn checked_from_u8(u8) -> Option<Length<T>>
+struct Length<T>(u8, PhantomData<T>);
+struct Meters<T>(u8, PhantomData<T>);
+struct Wrapper<T>(T);
+
+impl From<u8> for Wrapper<u8> {
+    fn from(val: u8) -> Self {
+        Self(val)
+    }
+}
+
+impl<T> From<Wrapper<u8>> for Meters<T> {
+    fn from(val: Wrapper<u8>) -> Self {
+        Self(val.0, PhantomData)
+    }
+}
+
+impl<T> From<Meters<T>> for Length<T> {
+    fn from(val: Meters<T>) -> Self {
+        Self(val.0, PhantomData)
+    }
+}
+
+#[test]
+fn test_distinct_check() {
+    assert_eq!(Pair::from((1, 2)), Pair([1, 2]));
+    assert_eq!(<(u8, u8)>::from(Pair([3, 4])), (3, 4));
+
+    assert_eq!(Length::<()>::from(5), Length(5, PhantomData));
+    assert_eq!(
+        Length::<()>::checked_from_u8(6),
+        Some(Length(6, PhantomData))
+    );
+}
@@ -0,0 +1,72 @@
+use transitive::Transitive;
+
+#[derive(Debug, PartialEq, Transitive)]
+struct Wrapper {
+    #[transitive(from(Source, Mid))] // impl From<Source> for Wrapper
+    #[transitive(try_from(i64, u32))] // impl TryFrom<i64> for Wrapper
+    inner: Inner,
+    #[transitive(default)]
+    hits: u32,
+}
+
+#[derive(Debug, PartialEq, Transitive)]
+struct Meters(#[transitive(from(f32))] f64); // impl From<f32> for Meters
+
+struct Source(u8);
+struct Mid(u8);
+#[derive(Debug, PartialEq)]
+struct Inner(u32);
+
+#[derive(Debug, PartialEq)]
+struct Zero;
+
+impl From<Source> for Mid {
+    fn from(val: Source) -> Self {
+        Self(val.0)
+    }
+}
+
+impl From<Mid> for Inner {
+    fn from(val: Mid) -> Self {
+        Self(val.0.into())
+    }
+}
+
+impl TryFrom<u32> for Inner {
+    type Error = Zero;
+
+    fn try_from(val: u32) -> Result<Self, Self::Error> {
+        match val {
+            0 => Err(Zero),
+            _ => Ok(Self(val)),
+        }
+    }
+}
+
+impl From<std::num::TryFromIntError> for Zero {
+    fn from(_: std::num::TryFromIntError) -> Self {
+        Self
+    }
+}
+
+#[test]
+pub fn test_field() {
+    assert_eq!(
+        Wrapper::from(Source(3)),
+        Wrapper {
+            inner: Inner(3),
+            hits: 0
+        }
+    );
+    assert_eq!(
+        Wrapper::try_from(4i64),
+        Ok(Wrapper {
+            inner: Inner(4),
+            hits: 0
+        })
+    );
+    assert_eq!(Wrapper::try_from(0i64), Err(Zero));
+    assert_eq!(Wrapper::try_from(-1i64), Err(Zero));
+
+    assert_eq!(Meters::from(1.5f32), Meters(1.5));
+}
@@ -0,0 +1,62 @@
+use std::marker::PhantomData;
+
+use transitive::Transitive;
+
+#[derive(Clone, Transitive)]
+#[transitive(display_via(Raw, String))] // impl Display for Id
+#[transitive(debug_via(Raw, u64))] // impl Debug for Id
+struct Id(u64);
+
+#[derive(Transitive)]
+#[transitive(display_via(Raw, String, ref))] // impl Display for Z<T>, by reference
+#[transitive(debug_via(Raw, u64, ref))] // impl Debug for Z<T>, by reference
+struct Z<T>(u64, PhantomData<T>);
+
+#[derive(Clone, Transitive)]
+#[transitive(display_via(Raw, fn hex, String))] // impl Display for Hash, through a function step
+struct Hash(u64);
+
+struct Raw(u64);
+
+impl From<Hash> for Raw {
+    fn from(value: Hash) -> Self {
+        Self(value.0)
+    }
+}
+
+fn hex(raw: Raw) -> String {
+    format!("{:#x}", raw.0)
+}
+
+impl From<Id> for Raw {
+    fn from(value: Id) -> Self {
+        Self(value.0)
+    }
+}
+
+impl<T> From<&Z<T>> for Raw {
+    fn from(value: &Z<T>) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<Raw> for String {
+    fn from(value: Raw) -> Self {
+        format!("id-{}", value.0)
+    }
+}
+
+impl From<Raw> for u64 {
+    fn from(value: Raw) -> Self {
+        value.0
+    }
+}
+
+#[test]
+pub fn test_fmt_via() {
+    assert_eq!(Id(1).to_string(), "id-1");
+    assert_eq!(format!("{:?}", Id(2)), "2");
+    assert_eq!(Z::<()>(3, PhantomData).to_string(), "id-3");
+    assert_eq!(format!("{:?}", Z::<()>(4, PhantomData)), "4");
+    assert_eq!(Hash(255).to_string(), "0xff");
+}
@@ -1,3 +1,6 @@
+// Lints of newer toolchains, unknown to the minimum supported one.
+#![allow(unknown_lints, clippy::duplicated_attributes)]
+
 mod macros;
 
 use std::marker::PhantomData;
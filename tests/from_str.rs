@@ -0,0 +1,96 @@
+use std::{convert::Infallible, marker::PhantomData, num::ParseIntError};
+
+use transitive::Transitive;
+
+#[derive(Transitive)]
+#[transitive(from_str(u64, Raw))] // impl FromStr for Typed
+struct Typed(u64);
+
+#[derive(Transitive)]
+#[transitive(from_str(u64, Raw, error = ConvErr))] // impl FromStr for Z<T>, with custom error
+struct Z<T>(PhantomData<T>);
+
+#[derive(Transitive)]
+#[transitive(from_str(u64))] // impl FromStr for Port
+struct Port(u16);
+
+struct Raw(u64);
+
+#[derive(Debug)]
+enum TypedErr {
+    Parse,
+    Zero,
+}
+
+#[derive(Debug)]
+struct ConvErr;
+
+impl From<u64> for Raw {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ParseIntError> for TypedErr {
+    fn from(_value: ParseIntError) -> Self {
+        Self::Parse
+    }
+}
+
+impl From<Infallible> for TypedErr {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+impl TryFrom<Raw> for Typed {
+    type Error = TypedErr;
+
+    fn try_from(value: Raw) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Err(TypedErr::Zero),
+            n => Ok(Self(n)),
+        }
+    }
+}
+
+impl From<ParseIntError> for ConvErr {
+    fn from(_value: ParseIntError) -> Self {
+        Self
+    }
+}
+
+impl From<Infallible> for ConvErr {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+impl<T> TryFrom<Raw> for Z<T> {
+    type Error = ConvErr;
+
+    fn try_from(_value: Raw) -> Result<Self, Self::Error> {
+        Ok(Self(PhantomData))
+    }
+}
+
+impl TryFrom<u64> for Port {
+    type Error = TypedErr;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Err(TypedErr::Zero),
+            n => Ok(Self(n as u16)),
+        }
+    }
+}
+
+#[test]
+pub fn test_from_str() {
+    assert_eq!("12".parse::<Typed>().unwrap().0, 12);
+    assert!(matches!("0".parse::<Typed>(), Err(TypedErr::Zero)));
+    assert!(matches!("a".parse::<Typed>(), Err(TypedErr::Parse)));
+    assert!("12".parse::<Z<()>>().is_ok());
+    assert_eq!("80".parse::<Port>().unwrap().0, 80);
+    assert!(matches!("0".parse::<Port>(), Err(TypedErr::Zero)));
+}
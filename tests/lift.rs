@@ -0,0 +1,113 @@
+mod macros;
+
+use transitive::Transitive;
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(from(D, C, B, lift(Option, Vec, Result, Box)))] // impl From<D> for A and lifted helpers
+#[transitive(into(B, C, D, lift(Option, Vec, Result, Box)))] // impl From<A> for D and lifted helpers
+struct A;
+#[derive(Debug, PartialEq)]
+struct B;
+#[derive(Debug, PartialEq)]
+struct C;
+#[derive(Debug, PartialEq)]
+struct D;
+
+impl_from!(B to A);
+impl_from!(C to B);
+impl_from!(D to C);
+
+impl_from!(A to B);
+impl_from!(B to C);
+impl_from!(C to D);
+
+mod try_lift {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Transitive)]
+    #[transitive(try_from(u8, Even, lift(Option, Vec, Result, Box)))] // impl TryFrom<u8> for Small
+    #[transitive(try_into(Even, u8, error = ConvErr, lift(Option, Vec, Result, Box)))] // impl TryFrom<Small> for u8
+    struct Small(u8);
+    struct Even(u8);
+
+    #[derive(Debug, PartialEq)]
+    struct ConvErr;
+
+    impl TryFrom<u8> for Even {
+        type Error = ConvErr;
+
+        fn try_from(value: u8) -> Result<Self, Self::Error> {
+            match value % 2 {
+                0 => Ok(Self(value)),
+                _ => Err(ConvErr),
+            }
+        }
+    }
+
+    impl TryFrom<Even> for Small {
+        type Error = ConvErr;
+
+        fn try_from(value: Even) -> Result<Self, Self::Error> {
+            match value.0 < 10 {
+                true => Ok(Self(value.0)),
+                false => Err(ConvErr),
+            }
+        }
+    }
+
+    impl TryFrom<Small> for Even {
+        type Error = ConvErr;
+
+        fn try_from(value: Small) -> Result<Self, Self::Error> {
+            Even::try_from(value.0)
+        }
+    }
+
+    impl From<Even> for u8 {
+        fn from(value: Even) -> Self {
+            value.0
+        }
+    }
+
+    impl From<std::convert::Infallible> for ConvErr {
+        fn from(value: std::convert::Infallible) -> Self {
+            match value {}
+        }
+    }
+
+    #[test]
+    pub fn test_try_lift() {
+        assert_eq!(Small::try_from_option_u8(Some(2)), Ok(Some(Small(2))));
+        assert_eq!(Small::try_from_option_u8(None), Ok(None));
+        assert_eq!(Small::try_from_option_u8(Some(3)), Err(ConvErr));
+        assert_eq!(
+            Small::try_from_vec_u8(vec![2, 4]),
+            Ok(vec![Small(2), Small(4)])
+        );
+        assert_eq!(Small::try_from_vec_u8(vec![2, 12]), Err(ConvErr));
+        assert_eq!(Small::try_from_result_u8::<()>(Ok(2)), Ok(Ok(Small(2))));
+        assert_eq!(Small::try_from_result_u8(Err(())), Ok(Err(())));
+        assert_eq!(
+            Box::<Small>::try_from(Box::new(2u8)),
+            Ok(Box::new(Small(2)))
+        );
+
+        assert_eq!(Small::try_into_option_u8(Some(Small(2))), Ok(Some(2)));
+        assert_eq!(Small::try_into_vec_u8(vec![Small(3)]), Err(ConvErr));
+        assert_eq!(Small::try_into_result_u8::<()>(Ok(Small(2))), Ok(Ok(2)));
+        assert_eq!(Small::try_into_box_u8(Box::new(Small(2))), Ok(Box::new(2)));
+    }
+}
+
+#[test]
+pub fn test_lift() {
+    assert_eq!(A::from_option_d(Some(D)), Some(A));
+    assert_eq!(A::from_vec_d(vec![D, D]), vec![A, A]);
+    assert_eq!(A::from_result_d::<()>(Ok(D)), Ok(A));
+    assert_eq!(Box::<A>::from(Box::new(D)), Box::new(A));
+
+    assert_eq!(A::into_option_d(Some(A)), Some(D));
+    assert_eq!(A::into_vec_d(vec![A]), vec![D]);
+    assert_eq!(A::into_result_d::<()>(Ok(A)), Ok(D));
+    assert_eq!(A::into_box_d(Box::new(A)), Box::new(D));
+}
@@ -0,0 +1,73 @@
+use std::marker::PhantomData;
+
+use transitive::Transitive;
+
+#[derive(Clone, Copy, Debug, PartialEq, Transitive)]
+#[transitive(ops_via(f64), ops(Add, Sub, Mul<f64>, Div<Centimeters>, Neg))]
+struct Meters(f64);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Centimeters(f64);
+
+impl From<Meters> for f64 {
+    fn from(value: Meters) -> Self {
+        value.0
+    }
+}
+
+impl From<f64> for Meters {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Centimeters> for f64 {
+    fn from(value: Centimeters) -> Self {
+        value.0 / 100.0
+    }
+}
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(ops_via(Raw, u8), ops(BitAnd, BitOr, Shl<u8>, Not))] // through an intermediate type
+struct Flags<T>(u8, PhantomData<T>);
+
+struct Raw(u8);
+
+impl<T> From<Flags<T>> for Raw {
+    fn from(value: Flags<T>) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<Raw> for u8 {
+    fn from(value: Raw) -> Self {
+        value.0
+    }
+}
+
+impl From<u8> for Raw {
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<Raw> for Flags<T> {
+    fn from(value: Raw) -> Self {
+        Self(value.0, PhantomData)
+    }
+}
+
+#[test]
+pub fn test_ops_via() {
+    assert_eq!(Meters(1.0) + Meters(2.0), Meters(3.0));
+    assert_eq!(Meters(3.0) - Meters(2.0), Meters(1.0));
+    assert_eq!(Meters(3.0) * 2.0, Meters(6.0));
+    assert_eq!(Meters(3.0) / Centimeters(50.0), Meters(6.0));
+    assert_eq!(-Meters(3.0), Meters(-3.0));
+
+    let flags = |val| Flags::<()>(val, PhantomData);
+    assert_eq!(flags(0b11) & flags(0b10), flags(0b10));
+    assert_eq!(flags(0b01) | flags(0b10), flags(0b11));
+    assert_eq!(flags(0b01) << 2u8, flags(0b100));
+    assert_eq!(!flags(0b1111_0000), flags(0b0000_1111));
+}
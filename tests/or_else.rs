@@ -0,0 +1,66 @@
+use transitive::Transitive;
+
+#[derive(Debug, Default, PartialEq, Transitive)]
+#[transitive(from_or_default(u32, Percent))] // impl From<u32> for Level
+#[transitive(from_or_else(i64, u32, Percent, error = LevelError, fallback = Level::from_error))] // impl From<i64> for Level
+#[transitive(from_or_else(fallback = Level::from_error, i32, u32, Percent, error = LevelError))] // impl From<i32> for Level
+struct Level(u8);
+struct Percent(u8);
+
+#[derive(Debug, PartialEq)]
+enum LevelError {
+    Negative,
+    NotPercent,
+    TooLarge,
+}
+
+impl Level {
+    fn from_error(err: LevelError) -> Self {
+        match err {
+            LevelError::Negative => Self(0),
+            LevelError::NotPercent | LevelError::TooLarge => Self(10),
+        }
+    }
+}
+
+impl From<std::num::TryFromIntError> for LevelError {
+    fn from(_: std::num::TryFromIntError) -> Self {
+        Self::Negative
+    }
+}
+
+impl TryFrom<u32> for Percent {
+    type Error = LevelError;
+
+    fn try_from(val: u32) -> Result<Self, Self::Error> {
+        match val {
+            0..=100 => Ok(Self(val as u8)),
+            _ => Err(LevelError::NotPercent),
+        }
+    }
+}
+
+impl TryFrom<Percent> for Level {
+    type Error = LevelError;
+
+    fn try_from(val: Percent) -> Result<Self, Self::Error> {
+        match val.0 {
+            0..=10 => Ok(Self(val.0)),
+            _ => Err(LevelError::TooLarge),
+        }
+    }
+}
+
+#[test]
+pub fn test_or_else() {
+    assert_eq!(Level::from(7u32), Level(7));
+    assert_eq!(Level::from(70u32), Level(0));
+    assert_eq!(Level::from(700u32), Level(0));
+
+    assert_eq!(Level::from(7i64), Level(7));
+    assert_eq!(Level::from(-7i64), Level(0));
+    assert_eq!(Level::from(70i64), Level(10));
+
+    assert_eq!(Level::from(7i32), Level(7));
+    assert_eq!(Level::from(-7i32), Level(0));
+}
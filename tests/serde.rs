@@ -0,0 +1,185 @@
+use std::{fmt::Display, marker::PhantomData};
+
+use serde::{Deserialize, Serialize};
+use transitive::Transitive;
+
+#[derive(Clone, Debug, PartialEq, Transitive)]
+#[transitive(serde_into(B, Wire))] // impl Serialize for A
+#[transitive(serde_from(Wire, B))] // impl Deserialize for A
+struct A(u64);
+struct B(u64);
+
+#[derive(Serialize, Deserialize)]
+struct Wire {
+    value: u64,
+}
+
+impl From<A> for B {
+    fn from(value: A) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<B> for Wire {
+    fn from(value: B) -> Self {
+        Self { value: value.0 }
+    }
+}
+
+impl From<Wire> for B {
+    fn from(value: Wire) -> Self {
+        Self(value.value)
+    }
+}
+
+impl From<B> for A {
+    fn from(value: B) -> Self {
+        Self(value.0)
+    }
+}
+
+#[derive(Transitive)]
+#[transitive(serde_into(B, Wire))] // impl Serialize for Z<'a, T>
+#[transitive(serde_from(Wire, B))] // impl Deserialize for Z<'a, T>
+struct Z<'a, T>(u64, PhantomData<&'a T>);
+
+impl<T> Clone for Z<'_, T> {
+    fn clone(&self) -> Self {
+        Self(self.0, PhantomData)
+    }
+}
+
+impl<T> From<Z<'_, T>> for B {
+    fn from(value: Z<'_, T>) -> Self {
+        Self(value.0)
+    }
+}
+
+impl<T> From<B> for Z<'_, T> {
+    fn from(value: B) -> Self {
+        Self(value.0, PhantomData)
+    }
+}
+
+mod try_serde {
+    use std::convert::Infallible;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Clone, Transitive)]
+    #[transitive(try_serde_into(u64, Even))] // impl Serialize for C
+    #[transitive(try_serde_from(u64, Even))] // impl Deserialize for C
+    struct C(u64);
+
+    #[derive(Debug, Clone, Transitive)]
+    #[transitive(try_serde_into(u64, Even, error = ConvErr))] // impl Serialize for D
+    #[transitive(try_serde_from(u64, Even, error = ConvErr))] // impl Deserialize for D
+    struct D(u64);
+
+    #[derive(Serialize)]
+    struct Even(u64);
+
+    #[derive(Debug)]
+    struct OddErr;
+
+    #[derive(Debug)]
+    struct ConvErr;
+
+    impl Display for OddErr {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "odd value")
+        }
+    }
+
+    impl Display for ConvErr {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "conversion failed")
+        }
+    }
+
+    impl From<Infallible> for OddErr {
+        fn from(value: Infallible) -> Self {
+            match value {}
+        }
+    }
+
+    impl From<Infallible> for ConvErr {
+        fn from(value: Infallible) -> Self {
+            match value {}
+        }
+    }
+
+    impl From<OddErr> for ConvErr {
+        fn from(_value: OddErr) -> Self {
+            Self
+        }
+    }
+
+    impl From<C> for u64 {
+        fn from(value: C) -> Self {
+            value.0
+        }
+    }
+
+    impl From<D> for u64 {
+        fn from(value: D) -> Self {
+            value.0
+        }
+    }
+
+    impl TryFrom<u64> for Even {
+        type Error = OddErr;
+
+        fn try_from(value: u64) -> Result<Self, Self::Error> {
+            match value % 2 {
+                0 => Ok(Self(value)),
+                _ => Err(OddErr),
+            }
+        }
+    }
+
+    impl TryFrom<Even> for C {
+        type Error = OddErr;
+
+        fn try_from(value: Even) -> Result<Self, Self::Error> {
+            Ok(Self(value.0))
+        }
+    }
+
+    impl TryFrom<Even> for D {
+        type Error = ConvErr;
+
+        fn try_from(value: Even) -> Result<Self, Self::Error> {
+            Ok(Self(value.0))
+        }
+    }
+
+    #[test]
+    pub fn test_try_serde() {
+        assert_eq!(serde_json::to_string(&C(2)).unwrap(), "2");
+        let err = serde_json::to_string(&C(3)).unwrap_err();
+        assert_eq!(err.to_string(), "odd value");
+
+        let c: C = serde_json::from_str("4").unwrap();
+        assert_eq!(c, C(4));
+        let err = serde_json::from_str::<C>("5").unwrap_err();
+        assert_eq!(err.to_string(), "odd value");
+
+        let err = serde_json::to_string(&D(3)).unwrap_err();
+        assert_eq!(err.to_string(), "conversion failed");
+        let err = serde_json::from_str::<D>("5").unwrap_err();
+        assert_eq!(err.to_string(), "conversion failed");
+    }
+}
+
+#[test]
+pub fn test_serde() {
+    assert_eq!(serde_json::to_string(&A(1)).unwrap(), r#"{"value":1}"#);
+    let a: A = serde_json::from_str(r#"{"value":2}"#).unwrap();
+    assert_eq!(a, A(2));
+
+    let z = Z::<()>(3, PhantomData);
+    assert_eq!(serde_json::to_string(&z).unwrap(), r#"{"value":3}"#);
+    let z: Z<()> = serde_json::from_str(r#"{"value":4}"#).unwrap();
+    assert_eq!(z.0, 4);
+}
@@ -0,0 +1,109 @@
+use std::{fmt::Display, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+transitive::serde_with!(mod secs = Timeout -> Secs -> u64);
+transitive::serde_with!(pub(crate) try mod even = Count -> Even -> u64);
+
+#[derive(Clone, Debug, PartialEq)]
+struct Timeout(Duration);
+struct Secs(u64);
+
+#[derive(Clone, Debug, PartialEq)]
+struct Count(u64);
+struct Even(u64);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "secs")]
+    timeout: Timeout,
+    #[serde(with = "even")]
+    count: Count,
+}
+
+impl From<Timeout> for Secs {
+    fn from(value: Timeout) -> Self {
+        Self(value.0.as_secs())
+    }
+}
+
+impl From<Secs> for u64 {
+    fn from(value: Secs) -> Self {
+        value.0
+    }
+}
+
+impl From<u64> for Secs {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Secs> for Timeout {
+    fn from(value: Secs) -> Self {
+        Self(Duration::from_secs(value.0))
+    }
+}
+
+#[derive(Debug)]
+struct OddErr;
+
+impl Display for OddErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "odd value")
+    }
+}
+
+impl TryFrom<Count> for Even {
+    type Error = OddErr;
+
+    fn try_from(value: Count) -> Result<Self, Self::Error> {
+        match value.0 % 2 {
+            0 => Ok(Self(value.0)),
+            _ => Err(OddErr),
+        }
+    }
+}
+
+impl TryFrom<u64> for Even {
+    type Error = OddErr;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Self::try_from(Count(value))
+    }
+}
+
+impl From<Even> for u64 {
+    fn from(value: Even) -> Self {
+        value.0
+    }
+}
+
+impl From<Even> for Count {
+    fn from(value: Even) -> Self {
+        Self(value.0)
+    }
+}
+
+#[test]
+pub fn test_serde_with() {
+    let config = Config {
+        timeout: Timeout(Duration::from_secs(5)),
+        count: Count(2),
+    };
+
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"timeout":5,"count":2}"#);
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+
+    let config = Config {
+        timeout: Timeout(Duration::from_secs(5)),
+        count: Count(3),
+    };
+
+    let err = serde_json::to_string(&config).unwrap_err();
+    assert_eq!(err.to_string(), "odd value");
+
+    let err = serde_json::from_str::<Config>(r#"{"timeout":5,"count":3}"#).unwrap_err();
+    assert!(err.to_string().starts_with("odd value"));
+}
@@ -0,0 +1,154 @@
+use std::{num::ParseIntError, time::Duration};
+
+use transitive::Transitive;
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(into(u64, fn Duration::from_secs, Timeout))] // impl From<Secs> for Timeout
+#[transitive(from(Timeout, fn Duration::from, via = |d: Duration| d.as_secs(), u64))] // impl From<Timeout> for Secs
+struct Secs(u64);
+#[derive(Debug, PartialEq)]
+struct Timeout(Duration);
+
+impl From<Secs> for u64 {
+    fn from(val: Secs) -> Self {
+        val.0
+    }
+}
+
+impl From<u64> for Secs {
+    fn from(val: u64) -> Self {
+        Self(val)
+    }
+}
+
+impl From<Duration> for Timeout {
+    fn from(val: Duration) -> Self {
+        Self(val)
+    }
+}
+
+impl From<Timeout> for Duration {
+    fn from(val: Timeout) -> Self {
+        val.0
+    }
+}
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(into(u64, fn double, u64, Doubled))] // impl From<Count> for Doubled, the step separating the repeated type
+struct Count(u64);
+#[derive(Debug, PartialEq)]
+struct Doubled(u64);
+
+impl From<Count> for u64 {
+    fn from(val: Count) -> Self {
+        val.0
+    }
+}
+
+impl From<u64> for Doubled {
+    fn from(val: u64) -> Self {
+        Self(val)
+    }
+}
+
+fn double(val: u64) -> u64 {
+    val * 2
+}
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(try_from(String, try fn parse_port, Port))] // impl TryFrom<String> for Endpoint
+#[transitive(try_into(Port, try via = |p: Port| u16::try_from(p.0), u16, error = EndpointError))] // impl TryFrom<Endpoint> for u16
+struct Endpoint(Port);
+#[derive(Debug, PartialEq)]
+struct Port(u32);
+
+#[derive(Debug, PartialEq)]
+enum EndpointError {
+    Parse(ParseIntError),
+    Range,
+}
+
+impl From<ParseIntError> for EndpointError {
+    fn from(val: ParseIntError) -> Self {
+        Self::Parse(val)
+    }
+}
+
+impl From<std::num::TryFromIntError> for EndpointError {
+    fn from(_: std::num::TryFromIntError) -> Self {
+        Self::Range
+    }
+}
+
+impl From<std::convert::Infallible> for EndpointError {
+    fn from(val: std::convert::Infallible) -> Self {
+        match val {}
+    }
+}
+
+#[derive(Debug, PartialEq, Transitive)]
+#[transitive(try_from(u64, fn Duration::from_secs, Timeout, error = EndpointError))] // impl TryFrom<u64> for Deadline
+struct Deadline(Timeout);
+
+impl TryFrom<Timeout> for Deadline {
+    type Error = EndpointError;
+
+    fn try_from(val: Timeout) -> Result<Self, Self::Error> {
+        match val.0.as_secs() {
+            0 => Err(EndpointError::Range),
+            _ => Ok(Self(val)),
+        }
+    }
+}
+
+fn parse_port(val: String) -> Result<u32, ParseIntError> {
+    val.parse()
+}
+
+impl TryFrom<u32> for Port {
+    type Error = EndpointError;
+
+    fn try_from(val: u32) -> Result<Self, Self::Error> {
+        Ok(Self(val))
+    }
+}
+
+impl TryFrom<Port> for Endpoint {
+    type Error = EndpointError;
+
+    fn try_from(val: Port) -> Result<Self, Self::Error> {
+        Ok(Self(val))
+    }
+}
+
+impl TryFrom<Endpoint> for Port {
+    type Error = EndpointError;
+
+    fn try_from(val: Endpoint) -> Result<Self, Self::Error> {
+        Ok(val.0)
+    }
+}
+
+#[test]
+pub fn test_steps() {
+    assert_eq!(Timeout::from(Secs(5)), Timeout(Duration::from_secs(5)));
+    assert_eq!(Secs::from(Timeout(Duration::from_secs(7))), Secs(7));
+    assert_eq!(Doubled::from(Count(4)), Doubled(8));
+
+    assert_eq!(Endpoint::try_from("80".to_owned()), Ok(Endpoint(Port(80))));
+    assert!(matches!(
+        Endpoint::try_from("eighty".to_owned()),
+        Err(EndpointError::Parse(_))
+    ));
+    assert_eq!(u16::try_from(Endpoint(Port(443))), Ok(443));
+    assert_eq!(
+        u16::try_from(Endpoint(Port(70000))),
+        Err(EndpointError::Range)
+    );
+
+    assert_eq!(
+        Deadline::try_from(3),
+        Ok(Deadline(Timeout(Duration::from_secs(3))))
+    );
+    assert_eq!(Deadline::try_from(0), Err(EndpointError::Range));
+}
@@ -1,3 +1,6 @@
+// Lints of newer toolchains, unknown to the minimum supported one.
+#![allow(unknown_lints, clippy::infallible_try_from)]
+
 mod macros;
 
 use transitive::Transitive;
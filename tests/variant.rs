@@ -0,0 +1,80 @@
+use std::num::ParseIntError;
+
+use transitive::Transitive;
+
+#[derive(Debug, PartialEq, Transitive)]
+enum AppError {
+    #[transitive(from(RawDb))] // impl From<RawDb> for AppError
+    Db(DbError),
+    #[transitive(try_from(String, try fn parse_code, error = ConvError))] // impl TryFrom<String> for AppError
+    #[transitive(try_from(i64, u32))] // impl TryFrom<i64> for AppError
+    Code { code: Code },
+}
+
+#[derive(Debug, PartialEq)]
+struct RawDb(u8);
+#[derive(Debug, PartialEq)]
+struct DbError(u8);
+#[derive(Debug, PartialEq)]
+struct Code(u32);
+
+#[derive(Debug, PartialEq)]
+enum ConvError {
+    Negative,
+    Parse,
+    Zero,
+}
+
+impl From<RawDb> for DbError {
+    fn from(val: RawDb) -> Self {
+        Self(val.0)
+    }
+}
+
+fn parse_code(val: String) -> Result<u32, ParseIntError> {
+    val.parse()
+}
+
+impl TryFrom<u32> for Code {
+    type Error = ConvError;
+
+    fn try_from(val: u32) -> Result<Self, Self::Error> {
+        match val {
+            0 => Err(ConvError::Zero),
+            _ => Ok(Self(val)),
+        }
+    }
+}
+
+impl From<std::num::TryFromIntError> for ConvError {
+    fn from(_: std::num::TryFromIntError) -> Self {
+        Self::Negative
+    }
+}
+
+impl From<ParseIntError> for ConvError {
+    fn from(_: ParseIntError) -> Self {
+        Self::Parse
+    }
+}
+
+#[test]
+pub fn test_variant() {
+    assert_eq!(AppError::from(RawDb(1)), AppError::Db(DbError(1)));
+
+    assert_eq!(
+        AppError::try_from("42".to_owned()),
+        Ok(AppError::Code { code: Code(42) })
+    );
+    assert_eq!(
+        AppError::try_from("forty-two".to_owned()),
+        Err(ConvError::Parse)
+    );
+
+    assert_eq!(
+        AppError::try_from(7i64),
+        Ok(AppError::Code { code: Code(7) })
+    );
+    assert_eq!(AppError::try_from(-7i64), Err(ConvError::Negative));
+    assert_eq!(AppError::try_from(0i64), Err(ConvError::Zero));
+}
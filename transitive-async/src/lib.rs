@@ -0,0 +1,39 @@
+//! Runtime companion of the [`transitive`](https://docs.rs/transitive) derive macros.
+//!
+//! A procedural macro crate cannot export traits, so the traits that the generated code relies on
+//! live here. Currently that is [`AsyncTryFrom`], which `#[transitive(async_try_from(..))]`
+//! implements by default.
+//!
+//! ```
+//! use transitive_async::AsyncTryFrom;
+//!
+//! struct UserId(u64);
+//!
+//! impl AsyncTryFrom<u64> for UserId {
+//!     type Error = std::convert::Infallible;
+//!
+//!     async fn async_try_from(val: u64) -> Result<Self, Self::Error> {
+//!         Ok(Self(val))
+//!     }
+//! }
+//!
+//! let _future = UserId::async_try_from(1);
+//! ```
+#![no_std]
+
+use core::future::Future;
+
+/// The async counterpart of [`TryFrom`], for conversions that need to await something, like
+/// resolving an ID through a cache.
+///
+/// Implementations can be written as an `async fn`. The returned future must be [`Send`], so that
+/// conversions can run on multi-threaded executors. Conversions that cannot guarantee that can go
+/// through a trait of your own instead, given to the derive through the `trait` and `method`
+/// options.
+pub trait AsyncTryFrom<T>: Sized {
+    /// The type returned in the event of a conversion error.
+    type Error;
+
+    /// Performs the conversion.
+    fn async_try_from(val: T) -> impl Future<Output = Result<Self, Self::Error>> + Send;
+}
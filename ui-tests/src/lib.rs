@@ -0,0 +1,2 @@
+//! UI tests of the `transitive` derive, run with `cargo test`. This library is empty: it only
+//! exists so that the features enabled on this package reach the compiled UI test cases.
@@ -0,0 +1,22 @@
+use transitive::Transitive;
+
+#[derive(Transitive)]
+#[transitive(from(D, C, B))] // `From<C> for B` is missing
+struct A;
+struct B;
+struct C;
+struct D;
+
+impl From<D> for C {
+    fn from(_: D) -> Self {
+        Self
+    }
+}
+
+impl From<B> for A {
+    fn from(_: B) -> Self {
+        Self
+    }
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+use std::marker::PhantomData;
+
+use transitive::Transitive;
+
+pub trait ConvertWith<T, Ctx> {
+    fn convert(val: T, ctx: &Ctx) -> Self;
+}
+
+pub struct Reg<T>(PhantomData<T>);
+pub struct Mid;
+
+// The context type names the generic parameter of the derived type.
+#[derive(Transitive)]
+#[transitive(from(u8, Mid), with_context(ctx: &Reg<T>), trait = ConvertWith, method = convert)]
+pub struct A<T>(PhantomData<T>);
+
+impl<T> ConvertWith<u8, Reg<T>> for Mid {
+    fn convert(_: u8, _: &Reg<T>) -> Self {
+        Self
+    }
+}
+
+impl<T> ConvertWith<Mid, Reg<T>> for A<T> {
+    fn convert(_: Mid, _: &Reg<T>) -> Self {
+        Self(PhantomData)
+    }
+}
+
+// The context type shares its name with a generic parameter of the hop checks.
+pub struct S;
+
+#[derive(Transitive)]
+#[transitive(from(u8, Mid), with_context(ctx: &S), trait = ConvertWith, method = convert)]
+pub struct B;
+
+impl ConvertWith<u8, S> for Mid {
+    fn convert(_: u8, _: &S) -> Self {
+        Self
+    }
+}
+
+impl ConvertWith<Mid, S> for B {
+    fn convert(_: Mid, _: &S) -> Self {
+        Self
+    }
+}
+
+fn main() {
+    let _ = A::convert(1, &Reg::<()>(PhantomData));
+    let _ = B::convert(1, &S);
+}
@@ -0,0 +1,14 @@
+// The `diagnostics` feature changes the wording of the missing impl errors, so the cases affected
+// by it have separate snapshots for each mode while the other cases run in both.
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    t.pass("tests/ui-pass/*.rs");
+
+    #[cfg(not(feature = "diagnostics"))]
+    t.compile_fail("tests/ui-default/*.rs");
+
+    #[cfg(feature = "diagnostics")]
+    t.compile_fail("tests/ui-diagnostics/*.rs");
+}
@@ -0,0 +1,8 @@
+use transitive::Transitive;
+
+#[derive(Transitive)]
+#[transitive(cast_ref(u64))] // `align(8)` gets skipped, but the struct is not `#[repr(transparent)]`
+#[repr(C, align(8))]
+struct Id(u64);
+
+fn main() {}
@@ -0,0 +1,12 @@
+use transitive::Transitive;
+
+#[derive(Transitive)]
+#[transitive(from(D, C, B), from(D, E, B))] // both generate `From<D> for A`
+#[transitive(into(B, C), into(E, C))] // both generate `From<A> for C`
+struct A;
+struct B;
+struct C;
+struct D;
+struct E;
+
+fn main() {}
@@ -0,0 +1,10 @@
+use transitive::Transitive;
+
+#[derive(Transitive)]
+#[transitive(deref_to(B, C, mut, D, as_ref, as_ref))] // both bad items are reported
+struct A;
+struct B;
+struct C;
+struct D;
+
+fn main() {}
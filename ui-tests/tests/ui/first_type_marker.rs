@@ -0,0 +1,19 @@
+use transitive::Transitive;
+
+trait Handler {}
+
+struct Echo;
+
+impl Handler for Echo {}
+
+#[derive(Transitive)]
+#[transitive(from(coerce Box<Echo>, Box<dyn Handler>))] // nothing converts into the first type
+struct Registered(Box<dyn Handler>);
+
+impl From<Box<dyn Handler>> for Registered {
+    fn from(val: Box<dyn Handler>) -> Self {
+        Self(val)
+    }
+}
+
+fn main() {}